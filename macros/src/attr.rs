@@ -0,0 +1,303 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{BinOp, Expr, ExprBinary, ExprUnary, GenericArgument, Item, ItemFn, Pat, Type, UnOp};
+
+/// Operator family which replaces the standard arithmetic operators.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Mode {
+    Checked,
+    Saturating,
+    Wrapping,
+}
+
+impl Mode {
+    fn name(self) -> &'static str {
+        match self {
+            Mode::Checked => "checked",
+            Mode::Saturating => "saturating",
+            Mode::Wrapping => "wrapping",
+        }
+    }
+}
+
+/// Expands an attribute macro.
+pub fn expand(mode: Mode, attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let message = format!("#[{}] does not accept arguments", mode.name());
+        return syn::Error::new(attr.span(), message).into_compile_error();
+    }
+
+    let mut item: ItemFn = match syn::parse2(item) {
+        Ok(item) => item,
+        Err(err) => return err.into_compile_error(),
+    };
+
+    let mut rewriter = Rewriter { mode, errors: Vec::new() };
+    rewriter.visit_block_mut(&mut item.block);
+    let errors = rewriter.errors.into_iter().map(syn::Error::into_compile_error);
+
+    quote! {
+        #(#errors)*
+        #item
+    }
+}
+
+/// Syntax tree visitor which replaces arithmetic operators.
+///
+/// Nested items, types, patterns and macro invocations are left unchanged. In checked mode,
+/// closures and async blocks are also left unchanged, since `?` would return from them rather than
+/// from the function.
+struct Rewriter {
+    mode: Mode,
+    errors: Vec<syn::Error>,
+}
+
+impl Rewriter {
+    /// Returns the path to an operator function, or `None` if the operator is not rewritten.
+    fn binary_fn(&self, op: &BinOp) -> Option<(TokenStream, Span, bool)> {
+        let (name, span, assign) = match *op {
+            BinOp::Add(ref t) => ("Add", t.span(), false),
+            BinOp::Sub(ref t) => ("Sub", t.span(), false),
+            BinOp::Mul(ref t) => ("Mul", t.span(), false),
+            BinOp::Div(ref t) => ("Div", t.span(), false),
+            BinOp::Rem(ref t) => ("Rem", t.span(), false),
+            BinOp::AddAssign(ref t) => ("Add", t.span(), true),
+            BinOp::SubAssign(ref t) => ("Sub", t.span(), true),
+            BinOp::MulAssign(ref t) => ("Mul", t.span(), true),
+            BinOp::DivAssign(ref t) => ("Div", t.span(), true),
+            BinOp::RemAssign(ref t) => ("Rem", t.span(), true),
+            _ => return None,
+        };
+
+        Some((self.operator_fn(name, span)?, span, assign))
+    }
+
+    /// Returns the path to the function for an operator, or `None` if the operator is not supported
+    /// by the current mode.
+    fn operator_fn(&self, name: &str, span: Span) -> Option<TokenStream> {
+        let (trait_prefix, fn_prefix) = match self.mode {
+            Mode::Checked => ("Try", "try"),
+            Mode::Saturating => ("Saturating", "saturating"),
+            Mode::Wrapping => ("Wrapping", "wrapping"),
+        };
+
+        if self.mode != Mode::Checked && (name == "Div" || name == "Rem") {
+            return None;
+        }
+
+        let trait_name = Ident::new(&format!("{}{}", trait_prefix, name), span);
+        let fn_name = Ident::new(&format!("{}_{}", fn_prefix, name.to_lowercase()), span);
+        Some(quote_spanned!(span=> ::ext_ops::#trait_name::#fn_name))
+    }
+
+    /// Returns the replacement for an expression, or `None` if it is not replaced.
+    fn rewrite(&mut self, expr: &Expr) -> Option<Expr> {
+        match *expr {
+            Expr::Binary(ExprBinary { ref left, ref op, ref right, .. }) => {
+                let Some((f, span, assign)) = self.binary_fn(op) else {
+                    self.check_supported(op);
+                    return None;
+                };
+                if !assign {
+                    let call = self.call(span, quote_spanned!(span=> #f(#left, #right)));
+                    return Some(syn::parse_quote_spanned!(span=> #call));
+                }
+                if is_simple_place(left) {
+                    let call = self.call(span, quote_spanned!(span=> #f(#left, #right)));
+                    return Some(syn::parse_quote_spanned!(span=> #left = #call));
+                }
+
+                // Other places are evaluated once, after the right operand, as with the built-in
+                // compound assignment operators for primitive types.
+                let rhs = Ident::new("rhs", Span::mixed_site());
+                let place = Ident::new("place", Span::mixed_site());
+                let call = self.call(span, quote_spanned!(span=> #f(*#place, #rhs)));
+                Some(syn::parse_quote_spanned! {span=> {
+                    let #rhs = #right;
+                    let #place = &mut #left;
+                    *#place = #call;
+                }})
+            },
+            Expr::Unary(ExprUnary { op: UnOp::Neg(ref t), ref expr, .. }) => {
+                // A negated literal is a single value, which may not be representable unnegated.
+                if let Expr::Lit(_) = **expr {
+                    return None;
+                }
+
+                let span = t.span();
+                let f = self.operator_fn("Neg", span)?;
+                let call = self.call(span, quote_spanned!(span=> #f(#expr)));
+                Some(syn::parse_quote_spanned!(span=> #call))
+            },
+            _ => None,
+        }
+    }
+
+    /// Completes a call to an operator function.
+    fn call(&self, span: Span, call: TokenStream) -> TokenStream {
+        match self.mode {
+            Mode::Checked => quote_spanned!(span=> #call?),
+            Mode::Saturating | Mode::Wrapping => call,
+        }
+    }
+
+    /// Reports an error if a binary operator is not supported by the current mode.
+    fn check_supported(&mut self, op: &BinOp) {
+        let unsupported = match *op {
+            BinOp::Div(_) | BinOp::DivAssign(_) => "division",
+            BinOp::Rem(_) | BinOp::RemAssign(_) => "remainder",
+            _ => return,
+        };
+
+        let message = format!("{} is not supported by #[{}]", unsupported, self.mode.name());
+        self.errors.push(syn::Error::new(op.span(), message));
+    }
+}
+
+/// Returns true if evaluating a place expression has no side effects, so it may be evaluated twice.
+fn is_simple_place(expr: &Expr) -> bool {
+    match *expr {
+        Expr::Path(_) => true,
+        Expr::Field(ref expr) => is_simple_place(&expr.base),
+        Expr::Paren(ref expr) => is_simple_place(&expr.expr),
+        _ => false,
+    }
+}
+
+impl VisitMut for Rewriter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match *expr {
+            Expr::Async(_) | Expr::Closure(_) if self.mode == Mode::Checked => return,
+            Expr::Const(_) | Expr::Macro(_) => return,
+            _ => (),
+        }
+
+        visit_mut::visit_expr_mut(self, expr);
+
+        if let Some(replacement) = self.rewrite(expr) {
+            *expr = replacement;
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+    fn visit_pat_mut(&mut self, _: &mut Pat) {}
+    fn visit_type_mut(&mut self, _: &mut Type) {}
+
+    fn visit_expr_repeat_mut(&mut self, expr: &mut syn::ExprRepeat) {
+        // The length is a constant expression.
+        self.visit_expr_mut(&mut expr.expr);
+    }
+
+    fn visit_generic_argument_mut(&mut self, arg: &mut GenericArgument) {
+        // Const generic arguments are constant expressions.
+        if let GenericArgument::Const(_) = *arg {
+            return;
+        }
+        visit_mut::visit_generic_argument_mut(self, arg);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+fn expand_str(mode: Mode, item: TokenStream) -> String {
+    expand(mode, TokenStream::new(), item).to_string()
+}
+
+#[test]
+fn test_checked_expand() {
+    let item = quote! {
+        fn f(a: u8, b: u8) -> Result<u8, ArithmeticError> {
+            let mut c = a * (b + 1) - -a / 2 % -3;
+            c += 1;
+            Ok(c)
+        }
+    };
+    let expected = quote! {
+        fn f(a: u8, b: u8) -> Result<u8, ArithmeticError> {
+            let mut c = ::ext_ops::TrySub::try_sub(
+                ::ext_ops::TryMul::try_mul(a, (::ext_ops::TryAdd::try_add(b, 1)?))?,
+                ::ext_ops::TryRem::try_rem(
+                    ::ext_ops::TryDiv::try_div(::ext_ops::TryNeg::try_neg(a)?, 2)?,
+                    -3
+                )?
+            )?;
+            c = ::ext_ops::TryAdd::try_add(c, 1)?;
+            Ok(c)
+        }
+    };
+    assert_eq!(expand_str(Mode::Checked, item), expected.to_string());
+}
+
+#[test]
+fn test_compound_assign_expand() {
+    let item = quote! {
+        fn f(v: &mut [u8], s: &mut S) -> Result<(), ArithmeticError> {
+            s.total *= 2;
+            v[next()] += v[0];
+            Ok(())
+        }
+    };
+    let expected = quote! {
+        fn f(v: &mut [u8], s: &mut S) -> Result<(), ArithmeticError> {
+            s.total = ::ext_ops::TryMul::try_mul(s.total, 2)?;
+            {
+                let rhs = v[0];
+                let place = &mut v[next()];
+                *place = ::ext_ops::TryAdd::try_add(*place, rhs)?;
+            };
+            Ok(())
+        }
+    };
+    assert_eq!(expand_str(Mode::Checked, item), expected.to_string());
+}
+
+#[test]
+fn test_checked_expand_skips() {
+    let item = quote! {
+        fn f(a: u8) -> Result<u8, ArithmeticError> {
+            const N: usize = 1 + 1;
+            let array = [0u8; N + 1];
+            let g = |x: u8| x + 1;
+            let s = format!("{}", a + 1);
+            let b = arr::<{ N + 1 }>();
+            let c = a.get::<{ N - 1 }>();
+            Ok(g(a))
+        }
+    };
+    assert_eq!(expand_str(Mode::Checked, item.clone()), item.to_string());
+}
+
+#[test]
+fn test_saturating_wrapping_expand() {
+    let item = quote! {
+        fn f(a: u8) -> u8 {
+            let g = |x: u8| x * 2;
+            -a + g(a)
+        }
+    };
+    let expected = quote! {
+        fn f(a: u8) -> u8 {
+            let g = |x: u8| ::ext_ops::SaturatingMul::saturating_mul(x, 2);
+            ::ext_ops::SaturatingAdd::saturating_add(
+                ::ext_ops::SaturatingNeg::saturating_neg(a),
+                g(a)
+            )
+        }
+    };
+    assert_eq!(expand_str(Mode::Saturating, item.clone()), expected.to_string());
+    assert!(expand_str(Mode::Wrapping, item).contains("WrappingAdd :: wrapping_add"));
+
+    let item = quote! { fn f(a: u8) -> u8 { a / 2 } };
+    assert!(expand_str(Mode::Wrapping, item).contains("compile_error"));
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Data, DeriveInput, Member, Type};
+
+/// Operator trait which can be derived.
+pub struct Op {
+    pub trait_name: &'static str,
+    pub fn_name: &'static str,
+    /// Whether the operator returns a `Result`.
+    pub checked: bool,
+    pub unary: bool,
+}
+
+/// Expands a derive macro.
+pub fn expand(op: &Op, input: TokenStream) -> TokenStream {
+    let input: DeriveInput = match syn::parse2(input) {
+        Ok(input) => input,
+        Err(err) => return err.into_compile_error(),
+    };
+
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => {
+            let message = format!("{} can only be derived for structs", op.trait_name);
+            return syn::Error::new(input.ident.span(), message).into_compile_error();
+        },
+    };
+
+    let trait_path = {
+        let trait_name = Ident::new(op.trait_name, Span::call_site());
+        quote!(::ext_ops::#trait_name)
+    };
+    let fn_name = Ident::new(op.fn_name, Span::call_site());
+    let members: Vec<Member> = fields.members().collect();
+    let types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+
+    // Errors are reported with the index of the field for which the operation failed. If every
+    // field has the same type, the error of that type is used. Otherwise, the errors are converted
+    // to `ArithmeticError`.
+    let common_type = match types.split_first() {
+        Some((first, rest)) if rest.iter().all(|ty| same_type(ty, first)) => Some(*first),
+        _ => None,
+    };
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for (i, ty) in types.iter().enumerate() {
+        if types[..i].iter().any(|prev| same_type(prev, ty)) {
+            continue;
+        }
+        where_clause.predicates.push(parse_quote!(#ty: #trait_path<Output = #ty>));
+        if op.checked && common_type.is_none() {
+            where_clause.predicates.push(parse_quote! {
+                <#ty as #trait_path>::Error: ::core::convert::Into<::ext_ops::ArithmeticError>
+            });
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let name = &input.ident;
+
+    let args: Vec<TokenStream> = members
+        .iter()
+        .map(|member| match op.unary {
+            false => quote!(self.#member, rhs.#member),
+            true => quote!(self.#member),
+        })
+        .collect();
+    let rhs_param = match op.unary {
+        false => quote!(, rhs: Self),
+        true => quote!(),
+    };
+
+    if !op.checked {
+        return quote! {
+            impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+                type Output = Self;
+
+                fn #fn_name(self #rhs_param) -> Self {
+                    Self { #(#members: #trait_path::#fn_name(#args),)* }
+                }
+            }
+        };
+    }
+
+    let (error, convert) = match common_type {
+        Some(ty) => (quote!(<#ty as #trait_path>::Error), quote!(err)),
+        None => (
+            quote!(::ext_ops::ArithmeticError),
+            quote!(::core::convert::Into::into(err)),
+        ),
+    };
+    let indices = 0..members.len();
+
+    quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            type Output = Self;
+            type Error = ::ext_ops::IndexedError<#error>;
+
+            fn #fn_name(self #rhs_param)
+                -> ::core::result::Result<Self, ::ext_ops::IndexedError<#error>>
+            {
+                ::core::result::Result::Ok(Self { #(
+                    #members: match #trait_path::#fn_name(#args) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(
+                                ::ext_ops::IndexedError::new(#indices, #convert)
+                            );
+                        },
+                    },
+                )* })
+            }
+        }
+    }
+}
+
+/// Returns true if two types are written identically.
+fn same_type(a: &Type, b: &Type) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_derive_enum() {
+    let op = Op { trait_name: "TryAdd", fn_name: "try_add", checked: true, unary: false };
+    let output = expand(&op, quote!(enum E { A(u8), B(u8) })).to_string();
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("TryAdd can only be derived for structs"));
+}
+
+#[test]
+fn test_derive_bounds() {
+    let op = Op {
+        trait_name: "WrappingAdd",
+        fn_name: "wrapping_add",
+        checked: false,
+        unary: false,
+    };
+    let output = expand(&op, quote!(struct S<T> { a: T, b: T, c: u8 })).to_string();
+    assert_eq!(output.matches("T : :: ext_ops :: WrappingAdd < Output = T >").count(), 1);
+    assert_eq!(output.matches("u8 : :: ext_ops :: WrappingAdd < Output = u8 >").count(), 1);
+}
@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Procedural macros for the `ext-ops` crate.
+//!
+//! This crate should be used through the `macros` feature of `ext-ops` rather than directly.
+
+mod attr;
+mod derive;
+
+use proc_macro::TokenStream;
+
+use crate::attr::Mode;
+use crate::derive::Op;
+
+/// Rewrites the arithmetic operators in a function body with checked operators.
+///
+/// Each binary `+`, `-`, `*`, `/` and `%`, each compound assignment such as `+=`, and each unary
+/// `-` is replaced with a call to the corresponding `Try*` trait followed by `?`, so the function
+/// must return a `Result` whose error type can be converted from the errors of the operators.
+/// A compound assignment evaluates its right operand and then its left operand once, as the
+/// built-in operators do for primitive types.
+///
+/// Nested items, macro invocations, closures and async blocks are left unchanged, as are negated
+/// literals such as `-128i8`.
+#[proc_macro_attribute]
+pub fn checked(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr::expand(Mode::Checked, attr.into(), item.into()).into()
+}
+
+/// Rewrites the arithmetic operators in a function body with saturating operators.
+///
+/// Each binary `+`, `-` and `*`, each corresponding compound assignment, and each unary `-` is
+/// replaced with a call to the corresponding `Saturating*` trait. Division and remainder are not
+/// supported.
+///
+/// Nested items and macro invocations are left unchanged, as are negated literals such as
+/// `-128i8`.
+#[proc_macro_attribute]
+pub fn saturating(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr::expand(Mode::Saturating, attr.into(), item.into()).into()
+}
+
+/// Rewrites the arithmetic operators in a function body with wrapping operators.
+///
+/// Each binary `+`, `-` and `*`, each corresponding compound assignment, and each unary `-` is
+/// replaced with a call to the corresponding `Wrapping*` trait. Division and remainder are not
+/// supported.
+///
+/// Nested items and macro invocations are left unchanged, as are negated literals such as
+/// `-128i8`.
+#[proc_macro_attribute]
+pub fn wrapping(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr::expand(Mode::Wrapping, attr.into(), item.into()).into()
+}
+
+/// Defines derive macros for operator traits.
+macro_rules! derive_ops {
+    { $($kind:ident $arity:ident $trait:ident::$method:ident => $fn:ident;)* } => { $(
+        #[doc = concat!(
+            "Derives `", stringify!($trait), "` for a struct by applying it to each field.",
+        )]
+        #[doc = "\nEnums and unions are not supported."]
+        #[doc = derive_ops!(@doc $kind)]
+        #[proc_macro_derive($trait)]
+        pub fn $fn(input: TokenStream) -> TokenStream {
+            let op = Op {
+                trait_name: stringify!($trait),
+                fn_name: stringify!($method),
+                checked: derive_ops!(@checked $kind),
+                unary: derive_ops!(@unary $arity),
+            };
+            derive::expand(&op, input.into()).into()
+        }
+    )* };
+
+    (@doc checked) => {
+        "\nThe error type is `IndexedError<E>`, where the index is that of the first field for \
+         which the operation failed. If every field has the same type, `E` is the error type of \
+         that type's operator. Otherwise, `E` is `ArithmeticError`."
+    };
+    (@doc infallible) => { "" };
+    (@checked checked) => { true };
+    (@checked infallible) => { false };
+    (@unary binary) => { false };
+    (@unary unary) => { true };
+}
+
+derive_ops! {
+    checked binary TryAdd::try_add => derive_try_add;
+    checked binary TryDiv::try_div => derive_try_div;
+    checked binary TryMul::try_mul => derive_try_mul;
+    checked unary TryNeg::try_neg => derive_try_neg;
+    checked binary TryRem::try_rem => derive_try_rem;
+    checked binary TrySub::try_sub => derive_try_sub;
+    infallible binary SaturatingAdd::saturating_add => derive_saturating_add;
+    infallible binary SaturatingMul::saturating_mul => derive_saturating_mul;
+    infallible unary SaturatingNeg::saturating_neg => derive_saturating_neg;
+    infallible binary SaturatingSub::saturating_sub => derive_saturating_sub;
+    infallible binary WrappingAdd::wrapping_add => derive_wrapping_add;
+    infallible binary WrappingMul::wrapping_mul => derive_wrapping_mul;
+    infallible unary WrappingNeg::wrapping_neg => derive_wrapping_neg;
+    infallible binary WrappingSub::wrapping_sub => derive_wrapping_sub;
+}
@@ -0,0 +1,223 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::IndexedError;
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+// Operators on arrays are applied element-wise. Binary operators accept either another array of the
+// same length or, for primitive element types, a scalar which is applied to every element. Checked
+// operators stop at the first element for which the operation fails and report its index.
+
+/// Applies a fallible function to each element of an array.
+fn try_map<T, R, E, const N: usize>(
+    array: [T; N],
+    mut f: impl FnMut(T) -> Result<R, E>,
+) -> Result<[R; N], IndexedError<E>> {
+    let mut elements = array.into_iter();
+    let mut error = None;
+
+    let results: [Option<R>; N] = core::array::from_fn(|index| {
+        match f(elements.next()?) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                error = Some(IndexedError::new(index, err));
+                // Leave the remaining elements unevaluated.
+                elements.by_ref().for_each(drop);
+                None
+            },
+        }
+    });
+
+    match error {
+        Some(err) => Err(err),
+        // Every element is `Some` if no error occurred.
+        None => Ok(results.map(|value| value.unwrap())),
+    }
+}
+
+/// Applies a fallible function to each pair of corresponding elements of two arrays.
+fn try_zip_map<T, U, R, E, const N: usize>(
+    lhs: [T; N],
+    rhs: [U; N],
+    mut f: impl FnMut(T, U) -> Result<R, E>,
+) -> Result<[R; N], IndexedError<E>> {
+    let mut rhs = rhs.into_iter();
+    // The arrays have the same length, so `rhs` is never exhausted first.
+    try_map(lhs, |lhs| f(lhs, rhs.next().unwrap()))
+}
+
+/// Applies a function to each pair of corresponding elements of two arrays.
+fn zip_map<T, U, R, const N: usize>(
+    lhs: [T; N],
+    rhs: [U; N],
+    mut f: impl FnMut(T, U) -> R,
+) -> [R; N] {
+    let mut rhs = rhs.into_iter();
+    // The arrays have the same length, so `rhs` is never exhausted first.
+    lhs.map(|lhs| f(lhs, rhs.next().unwrap()))
+}
+
+/// Implements checked unary operators for arrays.
+macro_rules! impl_try_unary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait, const N: usize> $trait for [T; N] {
+            type Output = [T::Output; N];
+            type Error = IndexedError<T::Error>;
+
+            fn $fn(self) -> Result<[T::Output; N], IndexedError<T::Error>> {
+                try_map(self, $trait::$fn)
+            }
+        }
+    )* };
+}
+
+/// Implements checked binary operators for arrays.
+macro_rules! impl_try_binary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait<U>, U, const N: usize> $trait<[U; N]> for [T; N] {
+            type Output = [T::Output; N];
+            type Error = IndexedError<T::Error>;
+
+            fn $fn(self, rhs: [U; N]) -> Result<[T::Output; N], IndexedError<T::Error>> {
+                try_zip_map(self, rhs, $trait::$fn)
+            }
+        }
+    )* };
+}
+
+/// Implements saturating or wrapping unary operators for arrays.
+macro_rules! impl_unary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait, const N: usize> $trait for [T; N] {
+            type Output = [T::Output; N];
+
+            fn $fn(self) -> [T::Output; N] {
+                self.map($trait::$fn)
+            }
+        }
+    )* };
+}
+
+/// Implements saturating or wrapping binary operators for arrays.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait<U>, U, const N: usize> $trait<[U; N]> for [T; N] {
+            type Output = [T::Output; N];
+
+            fn $fn(self, rhs: [U; N]) -> [T::Output; N] {
+                zip_map(self, rhs, $trait::$fn)
+            }
+        }
+    )* };
+}
+
+/// Implements binary operators between arrays of a primitive type and a scalar of the same type.
+macro_rules! impl_scalar_ops {
+    ($($ty:ident),*) => { $(
+        impl_scalar_ops! {
+            @try $ty;
+            impl TryAdd::try_add;
+            impl TryDiv::try_div;
+            impl TryMul::try_mul;
+            impl TryRem::try_rem;
+            impl TrySub::try_sub;
+        }
+
+        impl_scalar_ops! {
+            @infallible $ty;
+            impl SaturatingAdd::saturating_add;
+            impl SaturatingMul::saturating_mul;
+            impl SaturatingSub::saturating_sub;
+            impl WrappingAdd::wrapping_add;
+            impl WrappingMul::wrapping_mul;
+            impl WrappingSub::wrapping_sub;
+        }
+    )* };
+
+    { @try $ty:ident; $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<const N: usize> $trait<$ty> for [$ty; N] {
+            type Output = [$ty; N];
+            type Error = IndexedError<<$ty as $trait>::Error>;
+
+            fn $fn(self, rhs: $ty) -> Result<[$ty; N], Self::Error> {
+                try_map(self, |lhs| $trait::$fn(lhs, rhs))
+            }
+        }
+    )* };
+
+    { @infallible $ty:ident; $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<const N: usize> $trait<$ty> for [$ty; N] {
+            type Output = [$ty; N];
+
+            fn $fn(self, rhs: $ty) -> [$ty; N] {
+                self.map(|lhs| $trait::$fn(lhs, rhs))
+            }
+        }
+    )* };
+}
+
+impl_try_unary_ops! {
+    impl TryNeg::try_neg;
+}
+
+impl_try_binary_ops! {
+    impl TryAdd::try_add;
+    impl TryDiv::try_div;
+    impl TryMul::try_mul;
+    impl TryRem::try_rem;
+    impl TrySub::try_sub;
+}
+
+impl_unary_ops! {
+    impl SaturatingNeg::saturating_neg;
+    impl WrappingNeg::wrapping_neg;
+}
+
+impl_binary_ops! {
+    impl SaturatingAdd::saturating_add;
+    impl SaturatingMul::saturating_mul;
+    impl SaturatingSub::saturating_sub;
+    impl WrappingAdd::wrapping_add;
+    impl WrappingMul::wrapping_mul;
+    impl WrappingSub::wrapping_sub;
+}
+
+impl_scalar_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_array_try_ops() {
+    use crate::error::{Overflow, Undefined};
+
+    assert_eq!([1u8, 2, 3].try_add([10u8, 20, 30]), Ok([11, 22, 33]));
+    assert_eq!([1u8, 255, 3].try_add([1u8, 1, 255]), Err(IndexedError::new(1, Overflow)));
+    assert_eq!([10u8, 20, 30].try_div([1u8, 2, 0]), Err(IndexedError::new(2, Undefined)));
+    assert_eq!([1i8, -128].try_neg(), Err(IndexedError::new(1, Overflow)));
+    assert_eq!([0u8; 0].try_neg(), Ok([]));
+}
+
+#[test]
+fn test_array_scalar_ops() {
+    use crate::error::Overflow;
+
+    assert_eq!([1u8, 2, 3].try_mul(10u8), Ok([10, 20, 30]));
+    assert_eq!([1u8, 200, 3].try_mul(2u8), Err(IndexedError::new(1, Overflow)));
+    assert_eq!(SaturatingMul::saturating_mul([1u8, 200, 3], 2u8), [2, 255, 6]);
+    assert_eq!(WrappingMul::wrapping_mul([1u8, 200, 3], 2u8), [2, 144, 6]);
+}
+
+#[test]
+fn test_array_saturating_wrapping_ops() {
+    assert_eq!(SaturatingAdd::saturating_add([250u8, 1], [10u8, 1]), [255, 2]);
+    assert_eq!(SaturatingNeg::saturating_neg([-128i8, 1]), [127, -1]);
+    assert_eq!(WrappingAdd::wrapping_add([250u8, 1], [10u8, 1]), [4, 2]);
+    assert_eq!(WrappingNeg::wrapping_neg([-128i8, 1]), [-128, -1]);
+}
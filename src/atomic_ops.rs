@@ -0,0 +1,185 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+use core::sync::atomic::Ordering;
+
+use crate::saturating_ops::{SaturatingAdd, SaturatingSub};
+use crate::try_ops::{TryAdd, TryMul, TrySub};
+
+/// Checked read-modify-write operators for atomic integer types.
+///
+/// Each operator atomically replaces the current value with the result of a checked operation and
+/// returns the previous value. If the operation fails, the atomic value is left unchanged and the
+/// error is returned instead.
+///
+/// `order` describes the memory ordering of the successful update. The ordering of the loads which
+/// precede it is derived from `order` in the same way as for [compare_exchange][1] failures.
+///
+/// [1]: core::sync::atomic::AtomicU32::compare_exchange
+pub trait AtomicTryOps {
+    type Value: TryAdd + TryMul + TrySub;
+
+    fn try_fetch_add(&self, val: Self::Value, order: Ordering)
+        -> Result<Self::Value, <Self::Value as TryAdd>::Error>;
+    fn try_fetch_mul(&self, val: Self::Value, order: Ordering)
+        -> Result<Self::Value, <Self::Value as TryMul>::Error>;
+    fn try_fetch_sub(&self, val: Self::Value, order: Ordering)
+        -> Result<Self::Value, <Self::Value as TrySub>::Error>;
+}
+
+/// Saturating read-modify-write operators for atomic integer types.
+///
+/// Each operator atomically replaces the current value with the result of a saturating operation
+/// and returns the previous value.
+pub trait AtomicSaturatingOps {
+    type Value: SaturatingAdd + SaturatingSub;
+
+    fn saturating_fetch_add(&self, val: Self::Value, order: Ordering) -> Self::Value;
+    fn saturating_fetch_sub(&self, val: Self::Value, order: Ordering) -> Self::Value;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the ordering used to load the current value for a read-modify-write operation with the
+/// given success ordering.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        _ => order,
+    }
+}
+
+/// Implements an atomic read-modify-write operator with a compare-exchange loop.
+macro_rules! fetch_update {
+    ($atomic:expr, $order:expr, |$current:ident| $update:expr) => {{
+        let load_order = load_ordering($order);
+        let mut $current = $atomic.load(load_order);
+
+        loop {
+            let new = $update;
+
+            match $atomic.compare_exchange_weak($current, new, $order, load_order) {
+                Ok(prev) => break prev,
+                Err(actual) => $current = actual,
+            }
+        }
+    }};
+}
+
+/// Implements operators for atomic integer types.
+macro_rules! impl_atomic_ops {
+    ($($size:literal: $($atomic:ident: $ty:ident),*;)*) => { $($(
+        #[cfg(target_has_atomic = $size)]
+        impl AtomicTryOps for $atomic {
+            type Value = $ty;
+
+            fn try_fetch_add(&self, val: $ty, order: Ordering)
+                -> Result<$ty, <$ty as TryAdd>::Error>
+            {
+                Ok(fetch_update!(self, order, |current| TryAdd::try_add(current, val)?))
+            }
+
+            fn try_fetch_mul(&self, val: $ty, order: Ordering)
+                -> Result<$ty, <$ty as TryMul>::Error>
+            {
+                Ok(fetch_update!(self, order, |current| TryMul::try_mul(current, val)?))
+            }
+
+            fn try_fetch_sub(&self, val: $ty, order: Ordering)
+                -> Result<$ty, <$ty as TrySub>::Error>
+            {
+                Ok(fetch_update!(self, order, |current| TrySub::try_sub(current, val)?))
+            }
+        }
+
+        #[cfg(target_has_atomic = $size)]
+        impl AtomicSaturatingOps for $atomic {
+            type Value = $ty;
+
+            fn saturating_fetch_add(&self, val: $ty, order: Ordering) -> $ty {
+                fetch_update!(self, order, |current| SaturatingAdd::saturating_add(current, val))
+            }
+
+            fn saturating_fetch_sub(&self, val: $ty, order: Ordering) -> $ty {
+                fetch_update!(self, order, |current| SaturatingSub::saturating_sub(current, val))
+            }
+        }
+    )*)* };
+}
+
+impl_atomic_ops! {
+    "8": AtomicI8: i8, AtomicU8: u8;
+    "16": AtomicI16: i16, AtomicU16: u16;
+    "32": AtomicI32: i32, AtomicU32: u32;
+    "64": AtomicI64: i64, AtomicU64: u64;
+    "ptr": AtomicIsize: isize, AtomicUsize: usize;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(target_has_atomic = "8")]
+#[test]
+fn test_atomic_try_ops() {
+    use crate::error::{Overflow, RangeError, Underflow};
+
+    let n = AtomicU8::new(200);
+    assert_eq!(n.try_fetch_add(55, Ordering::SeqCst), Ok(200));
+    assert_eq!(n.try_fetch_add(1, Ordering::SeqCst), Err(Overflow));
+    assert_eq!(n.load(Ordering::SeqCst), 255);
+    assert_eq!(n.try_fetch_sub(255, Ordering::SeqCst), Ok(255));
+    assert_eq!(n.try_fetch_sub(1, Ordering::SeqCst), Err(Underflow));
+    assert_eq!(n.load(Ordering::SeqCst), 0);
+
+    let n = AtomicI8::new(16);
+    assert_eq!(n.try_fetch_mul(-8, Ordering::AcqRel), Ok(16));
+    assert_eq!(n.try_fetch_mul(2, Ordering::Release), Err(RangeError::Underflow));
+    assert_eq!(n.load(Ordering::Acquire), -128);
+}
+
+#[cfg(target_has_atomic = "8")]
+#[test]
+fn test_atomic_saturating_ops() {
+    let n = AtomicU8::new(200);
+    assert_eq!(n.saturating_fetch_add(100, Ordering::SeqCst), 200);
+    assert_eq!(n.load(Ordering::SeqCst), 255);
+    assert_eq!(n.saturating_fetch_sub(255, Ordering::SeqCst), 255);
+    assert_eq!(n.saturating_fetch_sub(1, Ordering::Relaxed), 0);
+    assert_eq!(n.load(Ordering::SeqCst), 0);
+
+    let n = AtomicI8::new(-100);
+    assert_eq!(n.saturating_fetch_sub(100, Ordering::SeqCst), -100);
+    assert_eq!(n.load(Ordering::SeqCst), -128);
+}
+
+#[cfg(all(feature = "std", target_has_atomic = "32"))]
+#[test]
+fn test_atomic_try_ops_contended() {
+    let n = AtomicU32::new(u32::MAX - 30_001);
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                while n.try_fetch_add(3, Ordering::Relaxed).is_ok() {}
+            });
+        }
+    });
+
+    // Every successful update adds exactly 3, and the last one must stop short of overflowing.
+    assert_eq!(n.load(Ordering::Relaxed), u32::MAX - 1);
+}
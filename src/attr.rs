@@ -0,0 +1,93 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Attribute macros which rewrite the arithmetic operators in a function body.
+//!
+//! These are the function-wide counterparts of the [checked!][crate::checked!],
+//! [saturating!][crate::saturating!] and [wrapping!][crate::wrapping!] expression macros, and are
+//! only available with the `macros` feature. They are declared in this module because attribute
+//! macros share a namespace with the expression macros at the crate root.
+
+pub use ext_ops_macros::{checked, saturating, wrapping};
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::attr::{checked, saturating, wrapping};
+    use crate::error::{ArithmeticError, Overflow};
+
+    #[checked]
+    fn interest(balance: u32, rate: u32, fee: u32) -> Result<u32, ArithmeticError> {
+        let mut total = balance + balance * rate / 100;
+        total -= fee;
+        Ok(total % 1_000_000)
+    }
+
+    #[checked]
+    fn negate(n: i8) -> Result<i8, Overflow> {
+        let n = -n;
+        Ok(n)
+    }
+
+    #[checked]
+    fn bump(values: &mut [u8], calls: &mut usize) -> Result<(), ArithmeticError> {
+        let mut next = || {
+            *calls += 1;
+            *calls
+        };
+        values[next()] += values[0];
+        Ok(())
+    }
+
+    #[saturating]
+    fn saturating_total(a: u8, b: u8) -> u8 {
+        let mut total = a * 2;
+        total += b;
+        total - 10
+    }
+
+    #[wrapping]
+    fn wrapping_total(a: i8, b: i8) -> i8 {
+        let mut total = a * 2;
+        total -= b;
+        -total
+    }
+
+    #[test]
+    fn test_checked_attr() {
+        assert_eq!(interest(1000, 5, 50), Ok(1000));
+        assert_eq!(interest(u32::MAX / 2, 5, 0), Err(ArithmeticError::Overflow));
+        assert_eq!(interest(10, 5, 11), Err(ArithmeticError::Underflow));
+        assert_eq!(negate(-127), Ok(127));
+        assert_eq!(negate(-128), Err(Overflow));
+    }
+
+    #[test]
+    fn test_checked_attr_compound_assign() {
+        let mut values = [100, 1, 2];
+        let mut calls = 0;
+        assert_eq!(bump(&mut values, &mut calls), Ok(()));
+        assert_eq!(values, [100, 101, 2]);
+        assert_eq!(calls, 1);
+
+        let mut values = [200, 100, 56];
+        assert_eq!(bump(&mut values, &mut calls), Err(ArithmeticError::Overflow));
+        assert_eq!(values, [200, 100, 56]);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_saturating_wrapping_attr() {
+        assert_eq!(saturating_total(100, 56), 245);
+        assert_eq!(saturating_total(200, 0), 245);
+        assert_eq!(saturating_total(3, 0), 0);
+        assert_eq!(wrapping_total(64, 0), -128);
+        assert_eq!(wrapping_total(64, 1), -127);
+    }
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::{Overflow, Underflow};
+use crate::try_ops::{TryAdd, TrySub};
+
+// The operators in this module treat the Unicode scalar values as a contiguous range by skipping
+// over the surrogate code points, so that '\u{D7FF}' + 1 == '\u{E000}'.
+
+/// First code point of the surrogate range.
+const SURROGATE_START: u32 = 0xD800;
+
+/// Number of code points in the surrogate range.
+const SURROGATE_LEN: u32 = 0x800;
+
+/// Index of `char::MAX` in the contiguous scalar value space.
+const MAX_INDEX: u32 = char::MAX as u32 - SURROGATE_LEN;
+
+/// Returns the index of a scalar value in the contiguous scalar value space.
+fn to_index(c: char) -> u32 {
+    let n = c as u32;
+    if n < SURROGATE_START { n } else { n - SURROGATE_LEN }
+}
+
+/// Returns the scalar value with the given index in the contiguous scalar value space.
+fn from_index(index: u32) -> char {
+    let n = if index < SURROGATE_START { index } else { index + SURROGATE_LEN };
+    // Never fails if `index <= MAX_INDEX`.
+    char::from_u32(n).unwrap_or(char::MAX)
+}
+
+/// Implements binary operators for reference types.
+macro_rules! impl_binary_ref_ops {
+    { $(impl $trait:ident<$rhs:ident>::$fn:ident for $ty:ident;)* } => { $(
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Returns the scalar value `rhs` positions after `self`.
+impl TryAdd<u32> for char {
+    type Output = char;
+    type Error = Overflow;
+
+    fn try_add(self, rhs: u32) -> Result<char, Overflow> {
+        match to_index(self).checked_add(rhs) {
+            Some(index) if index <= MAX_INDEX => Ok(from_index(index)),
+            _ => Err(Overflow),
+        }
+    }
+}
+
+/// Returns the scalar value `rhs` positions before `self`.
+impl TrySub<u32> for char {
+    type Output = char;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: u32) -> Result<char, Underflow> {
+        match to_index(self).checked_sub(rhs) {
+            None => Err(Underflow),
+            Some(index) => Ok(from_index(index)),
+        }
+    }
+}
+
+/// Returns the number of scalar values from `rhs` to `self`, or [Underflow] if `rhs` is greater
+/// than `self`.
+impl TrySub for char {
+    type Output = u32;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: char) -> Result<u32, Underflow> {
+        match to_index(self).checked_sub(to_index(rhs)) {
+            None => Err(Underflow),
+            Some(n) => Ok(n),
+        }
+    }
+}
+
+impl_binary_ref_ops! {
+    impl TryAdd<u32>::try_add for char;
+    impl TrySub<u32>::try_sub for char;
+    impl TrySub<char>::try_sub for char;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_char_try_add() {
+    assert_eq!(char::try_add('a', 2), Ok('c'));
+    assert_eq!(char::try_add('\u{D7FF}', 1), Ok('\u{E000}'));
+    assert_eq!(char::try_add('\u{D7FE}', 3), Ok('\u{E001}'));
+    assert_eq!(char::try_add(char::MAX, 0), Ok(char::MAX));
+    assert_eq!(char::try_add(char::MAX, 1), Err(Overflow));
+    assert_eq!(char::try_add('\0', MAX_INDEX), Ok(char::MAX));
+    assert_eq!(char::try_add('a', u32::MAX), Err(Overflow));
+}
+
+#[test]
+fn test_char_try_sub() {
+    assert_eq!(char::try_sub('c', 2), Ok('a'));
+    assert_eq!(char::try_sub('\u{E000}', 1), Ok('\u{D7FF}'));
+    assert_eq!(char::try_sub('\0', 0), Ok('\0'));
+    assert_eq!(char::try_sub('\0', 1), Err(Underflow));
+    assert_eq!(char::try_sub(char::MAX, MAX_INDEX), Ok('\0'));
+    assert_eq!(char::try_sub('c', 'a'), Ok(2));
+    assert_eq!(char::try_sub('\u{E000}', '\u{D7FF}'), Ok(1));
+    assert_eq!(char::try_sub(char::MAX, '\0'), Ok(MAX_INDEX));
+    assert_eq!(char::try_sub('a', 'c'), Err(Underflow));
+}
@@ -0,0 +1,195 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::mem;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::error::ArithmeticError;
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+
+/// Wrapper which implements the standard arithmetic operators with checked operators.
+///
+/// If an operation fails, the error is stored in the result and propagated through every operation
+/// which follows, so a whole expression can be evaluated before checking for errors. If both
+/// operands of a binary operator have failed, the error from the left operand is kept.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Checked<T>(Result<T, ArithmeticError>);
+
+impl<T> Checked<T> {
+    /// Wraps a value.
+    pub const fn new(value: T) -> Checked<T> {
+        Checked(Ok(value))
+    }
+
+    /// Returns the value, or the first error which occurred while computing it.
+    pub fn into_result(self) -> Result<T, ArithmeticError> {
+        self.0
+    }
+
+    /// Returns a reference to the value, or the first error which occurred while computing it.
+    pub fn as_result(&self) -> Result<&T, ArithmeticError> {
+        match self.0 {
+            Ok(ref value) => Ok(value),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Replaces the value with the result of an operator.
+    fn update(&mut self, f: impl FnOnce(Checked<T>) -> Checked<T>) {
+        // The operators never panic for primitive types, so the placeholder is overwritten. If the
+        // `Try*` operator of another type panics, the value is left as `Undefined`.
+        let value = mem::replace(self, Checked(Err(ArithmeticError::Undefined)));
+        *self = f(value);
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Checked<T> {
+        Checked::new(value)
+    }
+}
+
+/// Applies a checked binary operator to the values of two operands.
+fn apply<T, U, R, E: Into<ArithmeticError>>(
+    lhs: Checked<T>,
+    rhs: Checked<U>,
+    f: impl FnOnce(T, U) -> Result<R, E>,
+) -> Checked<R> {
+    Checked(match (lhs.0, rhs.0) {
+        (Ok(lhs), Ok(rhs)) => f(lhs, rhs).map_err(Into::into),
+        (Err(err), _) | (_, Err(err)) => Err(err),
+    })
+}
+
+/// Implements standard binary operators for `Checked<T>`.
+macro_rules! impl_binary_ops {
+    { $(impl $op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident
+        => $trait:ident::$fn:ident;)* } => { $(
+        impl<T, U> $op<Checked<U>> for Checked<T>
+        where
+            T: $trait<U>,
+            T::Error: Into<ArithmeticError>,
+        {
+            type Output = Checked<T::Output>;
+
+            fn $op_fn(self, rhs: Checked<U>) -> Checked<T::Output> {
+                apply(self, rhs, $trait::$fn)
+            }
+        }
+
+        impl<T, U> $assign<Checked<U>> for Checked<T>
+        where
+            T: $trait<U, Output = T>,
+            T::Error: Into<ArithmeticError>,
+        {
+            fn $assign_fn(&mut self, rhs: Checked<U>) {
+                self.update(|lhs| apply(lhs, rhs, $trait::$fn));
+            }
+        }
+    )* };
+}
+
+/// Implements standard binary operators between `Checked<T>` and unwrapped primitive values.
+macro_rules! impl_scalar_ops {
+    ($($ty:ident),*) => { $(
+        impl_scalar_ops! {
+            @impl $ty;
+            impl Add::add, AddAssign::add_assign;
+            impl Div::div, DivAssign::div_assign;
+            impl Mul::mul, MulAssign::mul_assign;
+            impl Rem::rem, RemAssign::rem_assign;
+            impl Sub::sub, SubAssign::sub_assign;
+        }
+    )* };
+
+    { @impl $ty:ident; $(impl $op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident;)* } => { $(
+        impl $op<$ty> for Checked<$ty> {
+            type Output = Checked<$ty>;
+
+            fn $op_fn(self, rhs: $ty) -> Checked<$ty> {
+                $op::$op_fn(self, Checked::new(rhs))
+            }
+        }
+
+        impl $op<Checked<$ty>> for $ty {
+            type Output = Checked<$ty>;
+
+            fn $op_fn(self, rhs: Checked<$ty>) -> Checked<$ty> {
+                $op::$op_fn(Checked::new(self), rhs)
+            }
+        }
+
+        impl $assign<$ty> for Checked<$ty> {
+            fn $assign_fn(&mut self, rhs: $ty) {
+                $assign::$assign_fn(self, Checked::new(rhs));
+            }
+        }
+    )* };
+}
+
+impl_binary_ops! {
+    impl Add::add, AddAssign::add_assign => TryAdd::try_add;
+    impl Div::div, DivAssign::div_assign => TryDiv::try_div;
+    impl Mul::mul, MulAssign::mul_assign => TryMul::try_mul;
+    impl Rem::rem, RemAssign::rem_assign => TryRem::try_rem;
+    impl Sub::sub, SubAssign::sub_assign => TrySub::try_sub;
+}
+
+impl<T> Neg for Checked<T>
+where
+    T: TryNeg,
+    T::Error: Into<ArithmeticError>,
+{
+    type Output = Checked<T::Output>;
+
+    fn neg(self) -> Checked<T::Output> {
+        Checked(self.0.and_then(|value| value.try_neg().map_err(Into::into)))
+    }
+}
+
+impl_scalar_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_checked_ops() {
+    let n = |n| Checked::new(n);
+
+    assert_eq!((n(100u8) * n(2u8) + n(55u8)).into_result(), Ok(255));
+    assert_eq!((n(100u8) * n(2u8) + n(56u8)).into_result(), Err(ArithmeticError::Overflow));
+    assert_eq!((n(1u8) - n(2u8) + n(5u8)).into_result(), Err(ArithmeticError::Underflow));
+    assert_eq!((n(10u8) / n(0u8) * n(0u8)).into_result(), Err(ArithmeticError::Undefined));
+    assert_eq!((n(10u8) % n(3u8)).into_result(), Ok(1));
+    assert_eq!((-Checked::new(-128i8)).into_result(), Err(ArithmeticError::Overflow));
+    assert_eq!((-(Checked::new(-127i8) - 1i8)).into_result(), Err(ArithmeticError::Overflow));
+
+    // The first error is kept, even if a later operation fails differently.
+    assert_eq!((n(0u8) - n(1u8)) / n(0u8), Checked(Err(ArithmeticError::Underflow)));
+    assert_eq!(n(0u8) / (n(255u8) + n(1u8)), Checked(Err(ArithmeticError::Overflow)));
+    assert_eq!((n(0u8) - n(1u8)) + (n(255u8) + n(1u8)), Checked(Err(ArithmeticError::Underflow)));
+}
+
+#[test]
+fn test_checked_scalar_ops() {
+    assert_eq!((Checked::new(100u8) * 2 + 55).into_result(), Ok(255));
+    assert_eq!((200u8 + Checked::new(56u8)).into_result(), Err(ArithmeticError::Overflow));
+    assert_eq!((Checked::new(-100i32) / 0).as_result(), Err(ArithmeticError::Undefined));
+}
+
+#[test]
+fn test_checked_assign_ops() {
+    let mut n = Checked::new(100u8);
+    n *= 2;
+    assert_eq!(n.as_result(), Ok(&200));
+    n += Checked::new(56);
+    assert_eq!(n.as_result(), Err(ArithmeticError::Overflow));
+    n -= 100;
+    assert_eq!(n.into_result(), Err(ArithmeticError::Overflow));
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+
+/// Addition operator which returns `None` if the operation fails.
+pub trait CheckedAdd<Rhs = Self> {
+    type Output;
+    fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Division operator which returns `None` if the operation fails.
+pub trait CheckedDiv<Rhs = Self> {
+    type Output;
+    fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Multiplication operator which returns `None` if the operation fails.
+pub trait CheckedMul<Rhs = Self> {
+    type Output;
+    fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Negation operator which returns `None` if the operation fails.
+pub trait CheckedNeg {
+    type Output;
+    fn checked_neg(self) -> Option<Self::Output>;
+}
+
+/// Remainder operator which returns `None` if the operation fails.
+pub trait CheckedRem<Rhs = Self> {
+    type Output;
+    fn checked_rem(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Subtraction operator which returns `None` if the operation fails.
+pub trait CheckedSub<Rhs = Self> {
+    type Output;
+    fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Implements checked operator traits for a type from its `checked_*` methods.
+///
+/// This is intended for types which provide `Option`-returning methods such as `checked_add`, but
+/// do not implement the corresponding `Try*` traits. Each operator is given as
+/// `impl Trait for Type => Error;`, where `Trait` is one of `TryAdd`, `TryDiv`, `TryMul`,
+/// `TryNeg`, `TryRem` and `TrySub`, and `Error` determines the `ArithmeticError` returned when the
+/// method returns `None`. Binary operators are implemented with `Type` on both sides.
+///
+/// `Error` is either a variant of `ArithmeticError`, e.g. `impl TryDiv for Meters => Undefined;`,
+/// or a closure-like classifier which is given references to the operands, e.g.
+/// `impl TryAdd for Meters => |lhs, rhs| ...;`. Operators which may fail in either direction, such
+/// as addition of signed values, should use a classifier, since a single variant would be wrong
+/// for one of the directions. The classifier is only evaluated when the method returns `None`, but
+/// the operands are cloned before the method is called, so the type must implement `Clone`.
+///
+/// The `checked_*` methods must be inherent methods of the type. The blanket [CheckedAdd] and
+/// related operators are implemented in terms of the operators defined here, so if no inherent
+/// method exists, the invocation fails to compile rather than calling them and recursing.
+#[macro_export]
+macro_rules! impl_try_ops_from_checked {
+    {} => {};
+    {
+        impl $trait:ident for $ty:ty => |$lhs:pat_param, $rhs:pat_param| $error:expr;
+        $($rest:tt)*
+    } => {
+        $crate::impl_try_ops_from_checked!(@$trait $ty, |$lhs, $rhs| $error);
+        $crate::impl_try_ops_from_checked! { $($rest)* }
+    };
+    { impl $trait:ident for $ty:ty => |$operand:pat_param| $error:expr; $($rest:tt)* } => {
+        $crate::impl_try_ops_from_checked!(@$trait $ty, |$operand| $error);
+        $crate::impl_try_ops_from_checked! { $($rest)* }
+    };
+    { impl $trait:ident for $ty:ty => $error:ident; $($rest:tt)* } => {
+        $crate::impl_try_ops_from_checked!(@$trait $ty, $error);
+        $crate::impl_try_ops_from_checked! { $($rest)* }
+    };
+
+    (@TryAdd $ty:ty, $($error:tt)*) => {
+        $crate::impl_try_ops_from_checked!(@binary TryAdd::try_add, checked_add, $ty, $($error)*);
+    };
+    (@TryDiv $ty:ty, $($error:tt)*) => {
+        $crate::impl_try_ops_from_checked!(@binary TryDiv::try_div, checked_div, $ty, $($error)*);
+    };
+    (@TryMul $ty:ty, $($error:tt)*) => {
+        $crate::impl_try_ops_from_checked!(@binary TryMul::try_mul, checked_mul, $ty, $($error)*);
+    };
+    (@TryRem $ty:ty, $($error:tt)*) => {
+        $crate::impl_try_ops_from_checked!(@binary TryRem::try_rem, checked_rem, $ty, $($error)*);
+    };
+    (@TrySub $ty:ty, $($error:tt)*) => {
+        $crate::impl_try_ops_from_checked!(@binary TrySub::try_sub, checked_sub, $ty, $($error)*);
+    };
+    (@TryNeg $ty:ty, $error:ident) => {
+        impl $crate::TryNeg for $ty {
+            type Output = $ty;
+            type Error = $crate::ArithmeticError;
+
+            fn try_neg(self) -> ::core::result::Result<$ty, $crate::ArithmeticError> {
+                $crate::impl_try_ops_from_checked!(@guard checked_neg, $ty);
+                self.checked_neg().ok_or($crate::ArithmeticError::$error)
+            }
+        }
+    };
+    (@TryNeg $ty:ty, |$operand:tt| $error:expr) => {
+        impl $crate::TryNeg for $ty {
+            type Output = $ty;
+            type Error = $crate::ArithmeticError;
+
+            fn try_neg(self) -> ::core::result::Result<$ty, $crate::ArithmeticError> {
+                $crate::impl_try_ops_from_checked!(@guard checked_neg, $ty);
+                match ::core::clone::Clone::clone(&self).checked_neg() {
+                    ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                    ::core::option::Option::None => {
+                        let $operand: &$ty = &self;
+                        ::core::result::Result::Err($error)
+                    },
+                }
+            }
+        }
+    };
+
+    (@binary $trait:ident::$fn:ident, $checked_fn:ident, $ty:ty, $error:ident) => {
+        impl $crate::$trait for $ty {
+            type Output = $ty;
+            type Error = $crate::ArithmeticError;
+
+            fn $fn(self, rhs: $ty) -> ::core::result::Result<$ty, $crate::ArithmeticError> {
+                $crate::impl_try_ops_from_checked!(@guard $checked_fn, $ty, $ty);
+                self.$checked_fn(rhs).ok_or($crate::ArithmeticError::$error)
+            }
+        }
+    };
+    (@binary $trait:ident::$fn:ident, $checked_fn:ident, $ty:ty,
+        |$lhs:tt, $rhs:tt| $error:expr) => {
+        impl $crate::$trait for $ty {
+            type Output = $ty;
+            type Error = $crate::ArithmeticError;
+
+            fn $fn(self, rhs: $ty) -> ::core::result::Result<$ty, $crate::ArithmeticError> {
+                $crate::impl_try_ops_from_checked!(@guard $checked_fn, $ty, $ty);
+                let lhs = self;
+                let result = ::core::clone::Clone::clone(&lhs)
+                    .$checked_fn(::core::clone::Clone::clone(&rhs));
+                match result {
+                    ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                    ::core::option::Option::None => {
+                        let ($lhs, $rhs): (&$ty, &$ty) = (&lhs, &rhs);
+                        ::core::result::Result::Err($error)
+                    },
+                }
+            }
+        }
+    };
+
+    // Declares a method with the same name as the blanket `Checked*` operator, which makes a call
+    // that would resolve to it either ambiguous or ill-typed. Inherent methods take precedence.
+    (@guard $checked_fn:ident, $ty:ty $(, $rhs:ty)?) => {
+        struct InherentCheckedMethodRequired;
+
+        #[allow(dead_code)]
+        trait CheckedMethodGuard {
+            fn $checked_fn(self $(, _: $rhs)?) -> InherentCheckedMethodRequired;
+        }
+
+        impl CheckedMethodGuard for $ty {
+            fn $checked_fn(self $(, _: $rhs)?) -> InherentCheckedMethodRequired {
+                InherentCheckedMethodRequired
+            }
+        }
+    };
+}
+
+/// A type without inherent `checked_*` methods is rejected, whether or not the blanket `Checked*`
+/// operators are in scope.
+///
+/// ```compile_fail,E0034
+/// use ext_ops::*;
+///
+/// struct Legacy(i8);
+///
+/// impl_try_ops_from_checked! {
+///     impl TryNeg for Legacy => Overflow;
+/// }
+/// ```
+///
+/// ```compile_fail,E0599
+/// use ext_ops::impl_try_ops_from_checked;
+///
+/// struct Legacy(i8);
+///
+/// impl_try_ops_from_checked! {
+///     impl TryNeg for Legacy => Overflow;
+/// }
+/// ```
+#[cfg(doctest)]
+pub struct ImplTryOpsFromCheckedWithoutMethods;
+
+//--------------------------------------------------------------------------------------------------
+
+// Option-returning operators are implemented for every type which implements the corresponding
+// checked operator, discarding the error.
+
+/// Implements binary `Checked*` operators.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident => $try_trait:ident::$try_fn:ident;)* } => { $(
+        impl<T: $try_trait<R>, R> $trait<R> for T {
+            type Output = T::Output;
+
+            fn $fn(self, rhs: R) -> Option<T::Output> {
+                $try_trait::$try_fn(self, rhs).ok()
+            }
+        }
+    )* };
+}
+
+impl_binary_ops! {
+    impl CheckedAdd::checked_add => TryAdd::try_add;
+    impl CheckedDiv::checked_div => TryDiv::try_div;
+    impl CheckedMul::checked_mul => TryMul::try_mul;
+    impl CheckedRem::checked_rem => TryRem::try_rem;
+    impl CheckedSub::checked_sub => TrySub::try_sub;
+}
+
+impl<T: TryNeg> CheckedNeg for T {
+    type Output = T::Output;
+
+    fn checked_neg(self) -> Option<T::Output> {
+        self.try_neg().ok()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::checked_ops::{CheckedAdd, CheckedDiv, CheckedNeg, CheckedSub};
+    use crate::error::ArithmeticError;
+    use crate::try_ops::{TryAdd, TryDiv, TryNeg};
+
+    /// Type which only provides `Option`-returning operators.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Legacy(i8);
+
+    impl Legacy {
+        fn checked_add(self, rhs: Legacy) -> Option<Legacy> {
+            self.0.checked_add(rhs.0).map(Legacy)
+        }
+
+        fn checked_div(self, rhs: Legacy) -> Option<Legacy> {
+            self.0.checked_div(rhs.0).map(Legacy)
+        }
+
+        fn checked_neg(self) -> Option<Legacy> {
+            self.0.checked_neg().map(Legacy)
+        }
+    }
+
+    /// Number of times the error of `Legacy::try_add` has been classified.
+    static CLASSIFIED: AtomicUsize = AtomicUsize::new(0);
+
+    impl_try_ops_from_checked! {
+        impl TryAdd for Legacy => |_, rhs| {
+            CLASSIFIED.fetch_add(1, Ordering::Relaxed);
+            match rhs.0 < 0 {
+                false => ArithmeticError::Overflow,
+                true => ArithmeticError::Underflow,
+            }
+        };
+        impl TryDiv for Legacy => Undefined;
+        impl TryNeg for Legacy => Overflow;
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(CheckedAdd::checked_add(200u8, 55), Some(255));
+        assert_eq!(CheckedAdd::checked_add(200u8, &56), None);
+        assert_eq!(CheckedSub::checked_sub(&-100i8, -27), Some(-73));
+        assert_eq!(CheckedSub::checked_sub(-100i8, 29), None);
+        assert_eq!(CheckedDiv::checked_div(1u32, 0), None);
+        assert_eq!(CheckedNeg::checked_neg(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_try_ops_from_checked() {
+        assert_eq!(Legacy(100).try_add(Legacy(27)), Ok(Legacy(127)));
+        assert_eq!(Legacy(100).try_add(Legacy(28)), Err(ArithmeticError::Overflow));
+        assert_eq!(Legacy(-100).try_add(Legacy(-29)), Err(ArithmeticError::Underflow));
+        assert_eq!(Legacy(-100).try_add(Legacy(-28)), Ok(Legacy(-128)));
+        assert_eq!(CLASSIFIED.load(Ordering::Relaxed), 2);
+        assert_eq!(Legacy(7).try_div(Legacy(0)), Err(ArithmeticError::Undefined));
+        assert_eq!(Legacy(-128).try_neg(), Err(ArithmeticError::Overflow));
+
+        // The adapted operators also provide the blanket `Checked*` operators.
+        assert_eq!(CheckedNeg::checked_neg(Legacy(5)), Some(Legacy(-5)));
+    }
+}
@@ -0,0 +1,204 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::fmt::{Debug, Display};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+/// Wrapper which implements the standard arithmetic operators with the same semantics as the
+/// built-in operators on primitive integers.
+///
+/// When `debug_assertions` are enabled, an addition, subtraction, multiplication or negation which
+/// fails panics with the error and the operands. Otherwise, it wraps around. Division and remainder
+/// always panic when they fail, and a remainder also panics when the corresponding division
+/// overflows, as in `i8::MIN % -1`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DebugChecked<T>(pub T);
+
+/// Applies a checked binary operator, panicking if it fails.
+#[track_caller]
+fn checked<T: Clone + Debug, E: Display>(
+    lhs: T,
+    rhs: T,
+    symbol: &str,
+    f: impl FnOnce(T, T) -> Result<T, E>,
+) -> T {
+    match f(lhs.clone(), rhs.clone()) {
+        Ok(value) => value,
+        Err(err) => panic!("{}: {:?} {} {:?}", err, lhs, symbol, rhs),
+    }
+}
+
+/// Implements standard binary operators which wrap around unless `debug_assertions` are enabled.
+macro_rules! impl_binary_ops {
+    { $(impl $op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident ($symbol:literal)
+        => $trait:ident::$fn:ident, $wrapping_trait:ident::$wrapping_fn:ident;)* } => { $(
+        impl<T> $op for DebugChecked<T>
+        where
+            T: $trait<Output = T> + $wrapping_trait<Output = T> + Clone + Debug,
+            T::Error: Display,
+        {
+            type Output = DebugChecked<T>;
+
+            #[track_caller]
+            fn $op_fn(self, rhs: DebugChecked<T>) -> DebugChecked<T> {
+                DebugChecked(match cfg!(debug_assertions) {
+                    false => $wrapping_trait::$wrapping_fn(self.0, rhs.0),
+                    true => checked(self.0, rhs.0, $symbol, $trait::$fn),
+                })
+            }
+        }
+
+        impl_binary_ops!(@assign $op::$op_fn, $assign::$assign_fn);
+    )* };
+
+    { $(impl $op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident ($symbol:literal)
+        => $trait:ident::$fn:ident;)* } => { $(
+        impl<T> $op for DebugChecked<T>
+        where
+            T: $trait<Output = T> + Clone + Debug,
+            T::Error: Display,
+        {
+            type Output = DebugChecked<T>;
+
+            #[track_caller]
+            fn $op_fn(self, rhs: DebugChecked<T>) -> DebugChecked<T> {
+                DebugChecked(checked(self.0, rhs.0, $symbol, $trait::$fn))
+            }
+        }
+
+        impl_binary_ops!(@assign $op::$op_fn, $assign::$assign_fn);
+    )* };
+
+    (@assign $op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident) => {
+        impl<T> $assign for DebugChecked<T>
+        where
+            DebugChecked<T>: $op<Output = DebugChecked<T>>,
+            T: Clone,
+        {
+            #[track_caller]
+            fn $assign_fn(&mut self, rhs: DebugChecked<T>) {
+                *self = $op::$op_fn(self.clone(), rhs);
+            }
+        }
+    };
+}
+
+impl_binary_ops! {
+    impl Add::add, AddAssign::add_assign ("+") => TryAdd::try_add, WrappingAdd::wrapping_add;
+    impl Mul::mul, MulAssign::mul_assign ("*") => TryMul::try_mul, WrappingMul::wrapping_mul;
+    impl Sub::sub, SubAssign::sub_assign ("-") => TrySub::try_sub, WrappingSub::wrapping_sub;
+}
+
+impl_binary_ops! {
+    impl Div::div, DivAssign::div_assign ("/") => TryDiv::try_div;
+}
+
+// A remainder such as `i8::MIN % -1` is representable, but the built-in operator panics because the
+// corresponding division overflows, so the division is checked first.
+impl<T> Rem for DebugChecked<T>
+where
+    T: TryDiv<Output = T> + TryRem<Output = T> + Clone + Debug,
+    <T as TryDiv>::Error: Display,
+    <T as TryRem>::Error: Display,
+{
+    type Output = DebugChecked<T>;
+
+    #[track_caller]
+    fn rem(self, rhs: DebugChecked<T>) -> DebugChecked<T> {
+        checked(self.0.clone(), rhs.0.clone(), "%", TryDiv::try_div);
+        DebugChecked(checked(self.0, rhs.0, "%", TryRem::try_rem))
+    }
+}
+
+impl_binary_ops!(@assign Rem::rem, RemAssign::rem_assign);
+
+impl<T> Neg for DebugChecked<T>
+where
+    T: TryNeg<Output = T> + WrappingNeg<Output = T> + Clone + Debug,
+    T::Error: Display,
+{
+    type Output = DebugChecked<T>;
+
+    #[track_caller]
+    fn neg(self) -> DebugChecked<T> {
+        if !cfg!(debug_assertions) {
+            return DebugChecked(self.0.wrapping_neg());
+        }
+        match self.0.clone().try_neg() {
+            Ok(value) => DebugChecked(value),
+            Err(err) => panic!("{}: -({:?})", err, self.0),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_debug_checked_ops() {
+    use DebugChecked as N;
+
+    assert_eq!(N(100u8) * N(2) + N(55), N(255));
+    assert_eq!(N(7i32) / N(-2) % N(2), N(-1));
+    assert_eq!(-N(-127i8), N(127));
+
+    let mut value = N(10u16);
+    value -= N(3);
+    value *= N(2);
+    value /= N(4);
+    assert_eq!(value, N(3));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_debug_checked_overflow() {
+    use std::panic::catch_unwind;
+
+    let message = |err: Box<dyn std::any::Any + Send>| err.downcast::<String>().map(|s| *s).ok();
+
+    let result = catch_unwind(|| DebugChecked(200u8) + DebugChecked(100));
+    match cfg!(debug_assertions) {
+        false => assert_eq!(result.ok(), Some(DebugChecked(44))),
+        true => assert_eq!(
+            result.map_err(message),
+            Err(Some(String::from("arithmetic overflow: 200 + 100"))),
+        ),
+    }
+
+    let result = catch_unwind(|| -DebugChecked(i8::MIN));
+    match cfg!(debug_assertions) {
+        false => assert_eq!(result.ok(), Some(DebugChecked(i8::MIN))),
+        true => assert_eq!(
+            result.map_err(message),
+            Err(Some(String::from("arithmetic overflow: -(-128)"))),
+        ),
+    }
+
+    let result = catch_unwind(|| DebugChecked(1u32) / DebugChecked(0));
+    assert_eq!(
+        result.map_err(message),
+        Err(Some(String::from("arithmetic result undefined: 1 / 0"))),
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_debug_checked_rem_overflow() {
+    use std::panic::catch_unwind;
+
+    let result = catch_unwind(|| DebugChecked(i8::MIN) % DebugChecked(-1));
+    assert_eq!(
+        result.map_err(|err| err.downcast::<String>().map(|s| *s).ok()),
+        Err(Some(String::from("arithmetic overflow: -128 % -1"))),
+    );
+    assert_eq!(DebugChecked(i8::MIN) % DebugChecked(3), DebugChecked(-2));
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+// Derive macros share their names with the traits which they implement, but not their namespace,
+// so both are exported from the crate root.
+
+pub use ext_ops_macros::{
+    SaturatingAdd,
+    SaturatingMul,
+    SaturatingNeg,
+    SaturatingSub,
+    TryAdd,
+    TryDiv,
+    TryMul,
+    TryNeg,
+    TryRem,
+    TrySub,
+    WrappingAdd,
+    WrappingMul,
+    WrappingNeg,
+    WrappingSub,
+};
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ArithmeticError, IndexedError, Overflow, RangeError, Undefined, Underflow};
+    // Imported from the crate root to include both the traits and the derive macros.
+    use crate::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+    use crate::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+    use crate::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+    #[derive(
+        Clone, Copy, Debug, Eq, PartialEq,
+        TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub,
+        SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub,
+        WrappingAdd, WrappingMul, WrappingNeg, WrappingSub,
+    )]
+    struct Vec3<T> {
+        x: T,
+        y: T,
+        z: T,
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, TryAdd, TrySub, SaturatingAdd, WrappingAdd)]
+    struct Money(i64, u32);
+
+    #[derive(Debug, Eq, PartialEq, TryAdd, WrappingNeg)]
+    struct Unit;
+
+    fn vec3<T>(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    #[test]
+    fn test_derive_try_ops() {
+        assert_eq!(vec3(1u8, 2, 3).try_add(vec3(10, 20, 30)), Ok(vec3(11, 22, 33)));
+        assert_eq!(vec3(1u8, 2, 255).try_add(vec3(10, 20, 30)),
+                   Err(IndexedError::new(2, Overflow)));
+        assert_eq!(vec3(1u8, 2, 3).try_sub(vec3(1, 3, 3)),
+                   Err(IndexedError::new(1, Underflow)));
+        assert_eq!(vec3(1u8, 2, 3).try_div(vec3(0, 1, 1)), Err(IndexedError::new(0, Undefined)));
+        assert_eq!(vec3(7u8, 8, 9).try_rem(vec3(2, 3, 4)), Ok(vec3(1, 2, 1)));
+        assert_eq!(vec3(16i8, 2, 3).try_mul(vec3(-8, 2, 3)), Ok(vec3(-128, 4, 9)));
+        assert_eq!(vec3(16i8, 2, 3).try_mul(vec3(8, 2, 3)),
+                   Err(IndexedError::new(0, RangeError::Overflow)));
+        assert_eq!(vec3(1i8, 2, -128).try_neg(), Err(IndexedError::new(2, Overflow)));
+
+        assert_eq!(Money(1, 2).try_add(Money(3, 4)), Ok(Money(4, 6)));
+        assert_eq!(Money(1, u32::MAX).try_add(Money(3, 4)),
+                   Err(IndexedError::new(1, ArithmeticError::Overflow)));
+        assert_eq!(Money(i64::MIN, 0).try_sub(Money(1, 0)),
+                   Err(IndexedError::new(0, ArithmeticError::Underflow)));
+        assert_eq!(Unit.try_add(Unit), Ok(Unit));
+    }
+
+    #[test]
+    fn test_derive_saturating_wrapping_ops() {
+        assert_eq!(vec3(250u8, 2, 3).saturating_add(vec3(10, 20, 30)), vec3(255, 22, 33));
+        assert_eq!(vec3(1u8, 2, 3).saturating_sub(vec3(2, 2, 2)), vec3(0, 0, 1));
+        assert_eq!(vec3(16u8, 2, 3).saturating_mul(vec3(16, 2, 3)), vec3(255, 4, 9));
+        assert_eq!(vec3(-128i8, 2, 3).saturating_neg(), vec3(127, -2, -3));
+        assert_eq!(vec3(250u8, 2, 3).wrapping_add(vec3(10, 20, 30)), vec3(4, 22, 33));
+        assert_eq!(vec3(1u8, 2, 3).wrapping_sub(vec3(2, 2, 2)), vec3(255, 0, 1));
+        assert_eq!(vec3(16u8, 2, 3).wrapping_mul(vec3(16, 2, 3)), vec3(0, 4, 9));
+        assert_eq!(vec3(-128i8, 2, 3).wrapping_neg(), vec3(-128, -2, -3));
+        assert_eq!(Money(i64::MAX, 1).saturating_add(Money(1, u32::MAX)),
+                   Money(i64::MAX, u32::MAX));
+        assert_eq!(Money(i64::MAX, 1).wrapping_add(Money(1, u32::MAX)), Money(i64::MIN, 0));
+        assert_eq!(Unit.wrapping_neg(), Unit);
+    }
+}
@@ -6,6 +6,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use core::convert::Infallible;
 use core::fmt::{Display, Formatter};
 
 /// Error raised when a checked arithmetic operation fails.
@@ -32,6 +33,12 @@ impl Display for ArithmeticError {
     }
 }
 
+impl From<Infallible> for ArithmeticError {
+    fn from(err: Infallible) -> ArithmeticError {
+        match err {}
+    }
+}
+
 impl From<Overflow> for ArithmeticError {
     fn from(_: Overflow) -> ArithmeticError {
         ArithmeticError::Overflow
@@ -64,6 +71,41 @@ impl std::error::Error for ArithmeticError {
     fn description(&self) -> &str { self.brief() }
 }
 
+/// Error raised when a checked element-wise operation fails, identifying the first element for
+/// which the operation failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IndexedError<E> {
+    /// Index of the element for which the operation failed.
+    pub index: usize,
+    /// Error raised by the operation on the failed element.
+    pub error: E,
+}
+
+impl<E> IndexedError<E> {
+    pub const fn new(index: usize, error: E) -> IndexedError<E> {
+        IndexedError { index, error }
+    }
+}
+
+impl<E: Display> Display for IndexedError<E> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{} at index {}", self.error, self.index)
+    }
+}
+
+impl<E: Into<ArithmeticError>> From<IndexedError<E>> for ArithmeticError {
+    fn from(err: IndexedError<E>) -> ArithmeticError {
+        err.error.into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for IndexedError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 /// Error raised when the result of a checked arithmetic operation is too high to be represented by
 /// the destination type.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -84,6 +126,61 @@ impl std::error::Error for Overflow {
     fn description(&self) -> &str { Overflow::BRIEF }
 }
 
+/// Error raised when an integer cannot be parsed from a string.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseIntError {
+    /// The input contains no digits.
+    Empty,
+    /// The input contains a character which is not a valid digit, sign, prefix, or separator.
+    InvalidDigit,
+    /// The input is a well-formed number which cannot be represented by the destination type.
+    OutOfRange(RangeError),
+}
+
+impl ParseIntError {
+    fn brief(self) -> &'static str {
+        match self {
+            ParseIntError::Empty => "cannot parse integer from empty string",
+            ParseIntError::InvalidDigit => "invalid digit found in string",
+            ParseIntError::OutOfRange(RangeError::Underflow) => {
+                "number too small to fit in target type"
+            },
+            ParseIntError::OutOfRange(RangeError::Overflow) => {
+                "number too large to fit in target type"
+            },
+        }
+    }
+}
+
+impl Display for ParseIntError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str(self.brief())
+    }
+}
+
+impl From<Overflow> for ParseIntError {
+    fn from(_: Overflow) -> ParseIntError {
+        ParseIntError::OutOfRange(RangeError::Overflow)
+    }
+}
+
+impl From<RangeError> for ParseIntError {
+    fn from(err: RangeError) -> ParseIntError {
+        ParseIntError::OutOfRange(err)
+    }
+}
+
+impl From<Underflow> for ParseIntError {
+    fn from(_: Underflow) -> ParseIntError {
+        ParseIntError::OutOfRange(RangeError::Underflow)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseIntError {
+    fn description(&self) -> &str { self.brief() }
+}
+
 /// Error raised when the result of a checked arithmetic operation is too high or too low to be
 /// represented by the destination type.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -0,0 +1,333 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/// Evaluates an arithmetic expression with checked operators.
+///
+/// The operators `+`, `-`, `*`, `/` and `%` and unary `-` are replaced with calls to the
+/// corresponding `Try*` traits, with the usual precedence and associativity. Operands may be
+/// parenthesized subexpressions, which may be followed by method calls or casts as in
+/// `(a + b).pow(2)`, or any other expressions which don't contain those operators at the top
+/// level, such as variables, literals, method calls and casts. Evaluation stops at the first
+/// operator which fails, and the error is converted to [ArithmeticError][crate::ArithmeticError].
+///
+/// Operators within the arguments, indices or blocks of an operand, as in `f(a + b)`, `v[i - 1]`
+/// or `f(-1)`, are not rewritten, so they are rejected with a compile error rather than being
+/// evaluated with the built-in operators. Such subexpressions must be evaluated separately, e.g.
+/// with a nested `checked!(a + b)?`; the contents of nested macro invocations are not checked.
+///
+/// The result has type `Result<T, ArithmeticError>`. A negated literal such as `-128i8` is treated
+/// as a single literal, as in ordinary Rust expressions.
+#[macro_export]
+macro_rules! checked {
+    ($($expr:tt)+) => {{
+        #[allow(unused_labels)]
+        let result = 'checked: {
+            ::core::result::Result::<_, $crate::ArithmeticError>::Ok(
+                $crate::__ext_ops_expr!(@sum (try 'checked) [] [] operand; $($expr)+)
+            )
+        };
+        result
+    }};
+}
+
+/// Evaluates an arithmetic expression with saturating operators.
+///
+/// The operators `+`, `-` and `*` and unary `-` are replaced with calls to the corresponding
+/// `Saturating*` traits, with the usual precedence and associativity. Operands are parsed as
+/// described for [checked!]. Division and remainder are not supported.
+#[macro_export]
+macro_rules! saturating {
+    ($($expr:tt)+) => {
+        $crate::__ext_ops_expr!(@sum (saturating) [] [] operand; $($expr)+)
+    };
+}
+
+/// Evaluates an arithmetic expression with wrapping operators.
+///
+/// The operators `+`, `-` and `*` and unary `-` are replaced with calls to the corresponding
+/// `Wrapping*` traits, with the usual precedence and associativity. Operands are parsed as
+/// described for [checked!]. Division and remainder are not supported.
+#[macro_export]
+macro_rules! wrapping {
+    ($($expr:tt)+) => {
+        $crate::__ext_ops_expr!(@sum (wrapping) [] [] operand; $($expr)+)
+    };
+}
+
+/// Implementation of the expression macros.
+///
+/// The first argument of each rule is the mode, which selects the operator traits. Sums are split
+/// into terms at each binary `+` or `-`, terms are split into factors at each `*`, `/` or `%`, and
+/// each list is then folded from the left. The `operand` and `operator` states record whether the
+/// next token begins an operand, which distinguishes unary `-` from binary `-`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ext_ops_expr {
+    // Splits a sum into terms.
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator; + $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)* ($($cur)+) Add] [] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator; - $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)* ($($cur)+) Sub] [] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator; * $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)*] [$($cur)+ *] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator; / $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)*] [$($cur)+ /] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator; % $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)*] [$($cur)+ %] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)*] operand; - $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)*] [$($cur)* -] operand; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)*] $state:ident; $t:tt $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@sum $m [$($terms)*] [$($cur)* $t] operator; $($rest)*)
+    };
+    (@sum $m:tt [$($terms:tt)*] [$($cur:tt)+] operator;) => {
+        $crate::__ext_ops_expr!(@fold_sum $m [$($terms)* ($($cur)+)])
+    };
+
+    // Folds the terms of a sum.
+    (@fold_sum $m:tt [($($first:tt)+) $($rest:tt)*]) => {
+        $crate::__ext_ops_expr!(@fold_sum $m {
+            $crate::__ext_ops_expr!(@product $m [] [] operand; $($first)+)
+        } $($rest)*)
+    };
+    (@fold_sum $m:tt $acc:tt $op:ident ($($term:tt)+) $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@fold_sum $m {
+            $crate::__ext_ops_expr!(@binary $m $op $acc {
+                $crate::__ext_ops_expr!(@product $m [] [] operand; $($term)+)
+            })
+        } $($rest)*)
+    };
+    (@fold_sum $m:tt $acc:tt) => { $acc };
+
+    // Splits a term into factors.
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)+] operator; * $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@product $m [$($factors)* ($($cur)+) Mul] [] operand; $($rest)*)
+    };
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)+] operator; / $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@product $m [$($factors)* ($($cur)+) Div] [] operand; $($rest)*)
+    };
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)+] operator; % $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@product $m [$($factors)* ($($cur)+) Rem] [] operand; $($rest)*)
+    };
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)*] operand; - $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@product $m [$($factors)*] [$($cur)* -] operand; $($rest)*)
+    };
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)*] $state:ident; $t:tt $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@product $m [$($factors)*] [$($cur)* $t] operator; $($rest)*)
+    };
+    (@product $m:tt [$($factors:tt)*] [$($cur:tt)+] operator;) => {
+        $crate::__ext_ops_expr!(@fold_product $m [$($factors)* ($($cur)+)])
+    };
+
+    // Folds the factors of a term.
+    (@fold_product $m:tt [($($first:tt)+) $($rest:tt)*]) => {
+        $crate::__ext_ops_expr!(@fold_product $m {
+            $crate::__ext_ops_expr!(@unary $m $($first)+)
+        } $($rest)*)
+    };
+    (@fold_product $m:tt $acc:tt $op:ident ($($factor:tt)+) $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@fold_product $m {
+            $crate::__ext_ops_expr!(@binary $m $op $acc {
+                $crate::__ext_ops_expr!(@unary $m $($factor)+)
+            })
+        } $($rest)*)
+    };
+    (@fold_product $m:tt $acc:tt) => { $acc };
+
+    // Evaluates a factor.
+    (@unary $m:tt - $operand:tt) => { $crate::__ext_ops_expr!(@neg $m $operand) };
+    (@unary $m:tt - $($operand:tt)+) => { $crate::__ext_ops_expr!(@neg_cast $m [] $($operand)+) };
+    (@unary $m:tt ($($expr:tt)+)) => {
+        $crate::__ext_ops_expr!(@sum $m [] [] operand; $($expr)+)
+    };
+    (@unary $m:tt ($($expr:tt)+) $($postfix:tt)+) => {
+        $crate::__ext_ops_expr!(@operand [
+            ($crate::__ext_ops_expr!(@sum $m [] [] operand; $($expr)+)) $($postfix)+
+        ] $($postfix)+)
+    };
+    (@unary $m:tt $($operand:tt)+) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($operand)+)
+    };
+    (@neg $m:tt $lit:literal) => { -$lit };
+    (@neg $m:tt $operand:tt) => {
+        $crate::__ext_ops_expr!(@unary_op $m { $crate::__ext_ops_expr!(@unary $m $operand) })
+    };
+
+    // Negates an operand which may be followed by casts. Negation binds more tightly than `as`, so
+    // `-a as u16` negates `a` and then casts the result.
+    (@neg_cast $m:tt [$($operand:tt)+] as $($cast:tt)+) => {
+        ($crate::__ext_ops_expr!(@neg_cast $m [] $($operand)+) as $($cast)+)
+    };
+    (@neg_cast $m:tt [$($operand:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@neg_cast $m [$($operand)* $t] $($rest)*)
+    };
+    (@neg_cast $m:tt [$operand:tt]) => { $crate::__ext_ops_expr!(@neg $m $operand) };
+    (@neg_cast $m:tt [$($operand:tt)+]) => {
+        $crate::__ext_ops_expr!(@unary_op $m {
+            $crate::__ext_ops_expr!(@unary $m $($operand)+)
+        })
+    };
+
+    // Emits an operand after checking that no groups within it contain arithmetic operators, which
+    // would otherwise be evaluated with the built-in operators. Macro invocations are skipped.
+    (@operand [$($operand:tt)+]) => { ($($operand)+) };
+    (@operand [$($operand:tt)+] $name:ident ! $group:tt $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($rest)*)
+    };
+    (@operand [$($operand:tt)+] ($($group:tt)*) $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($group)* $($rest)*)
+    };
+    (@operand [$($operand:tt)+] [$($group:tt)*] $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($group)* $($rest)*)
+    };
+    (@operand [$($operand:tt)+] {$($group:tt)*} $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($group)* $($rest)*)
+    };
+    (@operand [$($operand:tt)+] + $($rest:tt)*) => { $crate::__ext_ops_expr!(@nested_op) };
+    (@operand [$($operand:tt)+] - $($rest:tt)*) => { $crate::__ext_ops_expr!(@nested_op) };
+    (@operand [$($operand:tt)+] * $($rest:tt)*) => { $crate::__ext_ops_expr!(@nested_op) };
+    (@operand [$($operand:tt)+] / $($rest:tt)*) => { $crate::__ext_ops_expr!(@nested_op) };
+    (@operand [$($operand:tt)+] % $($rest:tt)*) => { $crate::__ext_ops_expr!(@nested_op) };
+    (@operand [$($operand:tt)+] $t:tt $($rest:tt)*) => {
+        $crate::__ext_ops_expr!(@operand [$($operand)+] $($rest)*)
+    };
+    (@nested_op) => {
+        ::core::compile_error!(
+            "arithmetic operators within the arguments, indices or blocks of an operand are not \
+             rewritten; evaluate the subexpression separately"
+        )
+    };
+
+    // Applies an operator.
+    (@binary (try $l:lifetime) $op:ident $lhs:tt $rhs:tt) => {
+        match $crate::__ext_ops_expr!(@try_fn $op)($lhs, $rhs) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(err) => {
+                break $l ::core::result::Result::Err(::core::convert::From::from(err));
+            },
+        }
+    };
+    (@binary (saturating) Add $lhs:tt $rhs:tt) => {
+        $crate::SaturatingAdd::saturating_add($lhs, $rhs)
+    };
+    (@binary (saturating) Mul $lhs:tt $rhs:tt) => {
+        $crate::SaturatingMul::saturating_mul($lhs, $rhs)
+    };
+    (@binary (saturating) Sub $lhs:tt $rhs:tt) => {
+        $crate::SaturatingSub::saturating_sub($lhs, $rhs)
+    };
+    (@binary (wrapping) Add $lhs:tt $rhs:tt) => {
+        $crate::WrappingAdd::wrapping_add($lhs, $rhs)
+    };
+    (@binary (wrapping) Mul $lhs:tt $rhs:tt) => {
+        $crate::WrappingMul::wrapping_mul($lhs, $rhs)
+    };
+    (@binary (wrapping) Sub $lhs:tt $rhs:tt) => {
+        $crate::WrappingSub::wrapping_sub($lhs, $rhs)
+    };
+    (@binary ($mode:ident) $op:ident $lhs:tt $rhs:tt) => {
+        ::core::compile_error!("division and remainder are only supported by checked!")
+    };
+    (@unary_op (try $l:lifetime) $operand:tt) => {
+        match $crate::TryNeg::try_neg($operand) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(err) => {
+                break $l ::core::result::Result::Err(::core::convert::From::from(err));
+            },
+        }
+    };
+    (@unary_op (saturating) $operand:tt) => { $crate::SaturatingNeg::saturating_neg($operand) };
+    (@unary_op (wrapping) $operand:tt) => { $crate::WrappingNeg::wrapping_neg($operand) };
+
+    (@try_fn Add) => { $crate::TryAdd::try_add };
+    (@try_fn Div) => { $crate::TryDiv::try_div };
+    (@try_fn Mul) => { $crate::TryMul::try_mul };
+    (@try_fn Rem) => { $crate::TryRem::try_rem };
+    (@try_fn Sub) => { $crate::TrySub::try_sub };
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_checked_macro() {
+    use crate::error::ArithmeticError;
+
+    let (a, b, c, d, e) = (10u8, 20u8, 55u8, 5u8, 0u8);
+    assert_eq!(checked!(a * b + c / d - -e), Ok(211));
+    assert_eq!(checked!(a * b + c), Ok(255));
+    assert_eq!(checked!(a * b + c + 1), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(a * (b + c)), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(a - b + c), Err(ArithmeticError::Underflow));
+    assert_eq!(checked!(c / (a - a)), Err(ArithmeticError::Undefined));
+    assert_eq!(checked!(c % 6 * 3 + 252), Ok(255));
+    assert_eq!(checked!(c - b - a), Ok(25));
+    assert_eq!(checked!(c / d / 2), Ok(5));
+    assert_eq!(checked!(a), Ok(10));
+    assert_eq!(checked!(a.pow(2) - 20i32 as u8 * 5 + 1), Ok(1));
+    assert_eq!(checked!((c + c).pow(1)), Ok(110));
+    assert_eq!(checked!((a * b + c * d).pow(1)), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(1 + (b - a).max(a) as u16), Ok(11));
+    assert_eq!(checked!((a - b) as u16), Err(ArithmeticError::Underflow));
+
+    let (a, b) = (-100i8, 28i8);
+    assert_eq!(checked!(-a - b), Ok(72));
+    assert_eq!(checked!(a - b - 1), Err(ArithmeticError::Underflow));
+    assert_eq!(checked!(-(a - b)), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(-128i8 + 1), Ok(-127));
+    assert_eq!(checked!(- -a), Ok(-100));
+    assert_eq!(checked!(2 * -b * 2), Ok(-112));
+
+    let a = 1i8;
+    assert_eq!(checked!(-a as u16), Ok(65535));
+    assert_eq!(checked!(-a.pow(2) as u16 as u32 + 1), Ok(65536));
+    assert_eq!(checked!(-1i8 as u16), Ok(65535));
+    assert_eq!(checked!(-i8::MIN as i16), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(- -a as u16), Ok(1));
+}
+
+#[test]
+fn test_checked_macro_short_circuits() {
+    use crate::error::ArithmeticError;
+
+    let mut calls = 0;
+    let mut f = |n: u8| {
+        calls += 1;
+        n
+    };
+    assert_eq!(checked!(255u8 + 1 + f(1)), Err(ArithmeticError::Overflow));
+    assert_eq!(checked!(f(1) + 1 + f(1)), Ok(3));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_saturating_wrapping_macros() {
+    let (a, b, c) = (10u8, 20u8, 56u8);
+    assert_eq!(saturating!(a * b + c), 255);
+    assert_eq!(saturating!(a - b + c), 56);
+    assert_eq!(saturating!(a * (b - c)), 0);
+    assert_eq!(wrapping!(a * b + c), 0);
+    assert_eq!(wrapping!(a - b + c), 46);
+    assert_eq!(wrapping!(-a), 246);
+    assert_eq!(saturating!((a * b + c).min(100)), 100);
+    assert_eq!(wrapping!((a - b).max(a)), 246);
+
+    let (a, b) = (-100i8, 28i8);
+    assert_eq!(saturating!(a - b - -1), -127);
+    assert_eq!(saturating!(-(a - b)), 127);
+    assert_eq!(wrapping!(a - b - 1), 127);
+
+    let a = i8::MIN;
+    assert_eq!(saturating!(-a as i16), 127);
+    assert_eq!(wrapping!(-a as i16), -128);
+    assert_eq!(saturating!(-a as i16 * 2), 254);
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::iter::{self, Product, Sum};
+
+use crate::error::IndexedError;
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul};
+use crate::try_ops::{TryAdd, TryMul};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul};
+
+/// Extension methods for summing or multiplying the items of an iterator with ext-ops operators.
+///
+/// The sum of an empty iterator is the sum of no items as defined by [Sum], and the product of an
+/// empty iterator is the product of no items as defined by [Product]. For primitive types, these
+/// are zero and one respectively.
+///
+/// Checked methods stop at the first item for which the operation fails, and report the error along
+/// with the index of that item.
+pub trait ExtOpsIteratorExt: Iterator + Sized {
+    fn try_sum(self) -> Result<Self::Item, IndexedError<<Self::Item as TryAdd>::Error>>
+    where
+        Self::Item: TryAdd<Output = Self::Item> + Sum,
+    {
+        self.enumerate().try_fold(iter::empty().sum(), |sum: Self::Item, (index, item)| {
+            sum.try_add(item).map_err(|err| IndexedError::new(index, err))
+        })
+    }
+
+    fn try_product(self) -> Result<Self::Item, IndexedError<<Self::Item as TryMul>::Error>>
+    where
+        Self::Item: TryMul<Output = Self::Item> + Product,
+    {
+        self.enumerate().try_fold(iter::empty().product(), |product: Self::Item, (index, item)| {
+            product.try_mul(item).map_err(|err| IndexedError::new(index, err))
+        })
+    }
+
+    fn saturating_sum(self) -> Self::Item
+    where
+        Self::Item: SaturatingAdd<Output = Self::Item> + Sum,
+    {
+        self.fold(iter::empty().sum(), SaturatingAdd::saturating_add)
+    }
+
+    fn saturating_product(self) -> Self::Item
+    where
+        Self::Item: SaturatingMul<Output = Self::Item> + Product,
+    {
+        self.fold(iter::empty().product(), SaturatingMul::saturating_mul)
+    }
+
+    fn wrapping_sum(self) -> Self::Item
+    where
+        Self::Item: WrappingAdd<Output = Self::Item> + Sum,
+    {
+        self.fold(iter::empty().sum(), WrappingAdd::wrapping_add)
+    }
+
+    fn wrapping_product(self) -> Self::Item
+    where
+        Self::Item: WrappingMul<Output = Self::Item> + Product,
+    {
+        self.fold(iter::empty().product(), WrappingMul::wrapping_mul)
+    }
+}
+
+impl<I: Iterator> ExtOpsIteratorExt for I {}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_iter_try_ops() {
+    use crate::error::{Overflow, RangeError};
+
+    assert_eq!([100u8, 100, 55].into_iter().try_sum(), Ok(255));
+    assert_eq!([100u8, 100, 56, 1].into_iter().try_sum(), Err(IndexedError::new(2, Overflow)));
+    assert_eq!([-100i8, -28, -1].into_iter().try_sum(),
+               Err(IndexedError::new(2, RangeError::Underflow)));
+    assert_eq!(iter::empty::<u8>().try_sum(), Ok(0));
+    assert_eq!([2u8, 3, 5].into_iter().try_product(), Ok(30));
+    assert_eq!([16u8, 2, 8].into_iter().try_product(), Err(IndexedError::new(2, Overflow)));
+    assert_eq!(iter::empty::<u8>().try_product(), Ok(1));
+    assert_eq!((1..=20u64).try_product(), Ok(2_432_902_008_176_640_000));
+    assert_eq!((1..=21u64).try_product(), Err(IndexedError::new(20, Overflow)));
+}
+
+#[test]
+fn test_iter_saturating_wrapping_ops() {
+    assert_eq!([200u8, 100, 1].into_iter().saturating_sum(), 255);
+    assert_eq!([-100i8, -100, 50].into_iter().saturating_sum(), -78);
+    assert_eq!([16u8, 16].into_iter().saturating_product(), 255);
+    assert_eq!(iter::empty::<u8>().saturating_product(), 1);
+    assert_eq!([200u8, 100, 1].into_iter().wrapping_sum(), 45);
+    assert_eq!([16u8, 16, 3].into_iter().wrapping_product(), 0);
+    assert_eq!(iter::empty::<i8>().wrapping_sum(), 0);
+}
@@ -10,24 +10,112 @@
 
 //! General purpose arithmetic operator traits which are missing from the standard library.
 
+#[cfg(all(test, feature = "macros"))]
+extern crate self as ext_ops;
+
+mod array_ops;
+mod atomic_ops;
+mod char_ops;
+mod checked;
+mod checked_ops;
+mod debug_checked;
+#[cfg(feature = "macros")]
+mod derive;
 mod error;
+mod expr_macros;
+mod iter_ops;
+mod lift_ops;
+mod mode_ops;
+#[cfg(feature = "std")]
+mod net_ops;
+mod newtype;
+mod nonzero_ops;
+#[cfg(feature = "rayon")]
+mod par_ops;
+mod parse;
 mod saturating_ops;
+mod strict_ops;
+mod time_ops;
 mod try_ops;
+mod tuple_ops;
+mod ulp_ops;
+mod unchecked_ops;
+mod wrapper_ops;
 mod wrapping_ops;
 
+#[cfg(feature = "macros")]
+pub mod attr;
+pub mod policy;
+pub mod slice;
+
+pub use atomic_ops::{
+    AtomicSaturatingOps,
+    AtomicTryOps,
+};
+pub use checked::Checked;
+pub use checked_ops::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedNeg,
+    CheckedRem,
+    CheckedSub,
+};
+pub use debug_checked::DebugChecked;
+#[cfg(feature = "macros")]
+pub use derive::{
+    SaturatingAdd,
+    SaturatingMul,
+    SaturatingNeg,
+    SaturatingSub,
+    TryAdd,
+    TryDiv,
+    TryMul,
+    TryNeg,
+    TryRem,
+    TrySub,
+    WrappingAdd,
+    WrappingMul,
+    WrappingNeg,
+    WrappingSub,
+};
 pub use error::{
     ArithmeticError,
+    IndexedError,
     Overflow,
+    ParseIntError,
     RangeError,
     Undefined,
     Underflow,
 };
+pub use iter_ops::ExtOpsIteratorExt;
+pub use mode_ops::{
+    ModalAdd,
+    ModalMul,
+    ModalNeg,
+    ModalSub,
+    OverflowMode,
+};
+#[cfg(feature = "rayon")]
+pub use par_ops::{
+    ExtOpsParallelIteratorExt,
+    ParallelReduce,
+};
+pub use parse::TryParse;
 pub use saturating_ops::{
     SaturatingAdd,
     SaturatingMul,
     SaturatingNeg,
     SaturatingSub,
 };
+pub use strict_ops::{
+    StrictAdd,
+    StrictDiv,
+    StrictMul,
+    StrictNeg,
+    StrictRem,
+    StrictSub,
+};
 pub use try_ops::{
     TryAdd,
     TryDiv,
@@ -36,6 +124,20 @@ pub use try_ops::{
     TryRem,
     TrySub,
 };
+pub use ulp_ops::{
+    TryNextDown,
+    TryNextUp,
+    TryStepUlps,
+    UlpDistance,
+};
+pub use unchecked_ops::{
+    UncheckedAdd,
+    UncheckedDiv,
+    UncheckedMul,
+    UncheckedNeg,
+    UncheckedRem,
+    UncheckedSub,
+};
 pub use wrapping_ops::{
     WrappingAdd,
     WrappingMul,
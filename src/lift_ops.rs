@@ -0,0 +1,204 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::ArithmeticError;
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+// Operators on `Option<T>` apply the operator to the contained values if both operands are `Some`,
+// and otherwise return `None`. A failed checked operator is still reported as an error.
+//
+// Checked operators on `Result<T, E>` apply the operator to the contained value if the left
+// operand is `Ok`, and otherwise propagate the error. All errors are converted to
+// `ArithmeticError`, so checked operations can be chained without using `?` after each one.
+
+/// Implements checked unary operators for `Option<T>`.
+macro_rules! impl_option_try_unary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait> $trait for Option<T> {
+            type Output = Option<T::Output>;
+            type Error = T::Error;
+
+            fn $fn(self) -> Result<Option<T::Output>, T::Error> {
+                match self {
+                    None => Ok(None),
+                    Some(value) => value.$fn().map(Some),
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements checked binary operators for `Option<T>`.
+macro_rules! impl_option_try_binary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait<U>, U> $trait<Option<U>> for Option<T> {
+            type Output = Option<T::Output>;
+            type Error = T::Error;
+
+            fn $fn(self, rhs: Option<U>) -> Result<Option<T::Output>, T::Error> {
+                match (self, rhs) {
+                    (Some(lhs), Some(rhs)) => lhs.$fn(rhs).map(Some),
+                    _ => Ok(None),
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements saturating or wrapping unary operators for `Option<T>`.
+macro_rules! impl_option_unary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait> $trait for Option<T> {
+            type Output = Option<T::Output>;
+
+            fn $fn(self) -> Option<T::Output> {
+                self.map($trait::$fn)
+            }
+        }
+    )* };
+}
+
+/// Implements saturating or wrapping binary operators for `Option<T>`.
+macro_rules! impl_option_binary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T: $trait<U>, U> $trait<Option<U>> for Option<T> {
+            type Output = Option<T::Output>;
+
+            fn $fn(self, rhs: Option<U>) -> Option<T::Output> {
+                match (self, rhs) {
+                    (Some(lhs), Some(rhs)) => Some(lhs.$fn(rhs)),
+                    _ => None,
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements checked unary operators for `Result<T, E>`.
+macro_rules! impl_result_try_unary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T, E> $trait for Result<T, E>
+        where
+            T: $trait,
+            T::Error: Into<ArithmeticError>,
+            E: Into<ArithmeticError>,
+        {
+            type Output = T::Output;
+            type Error = ArithmeticError;
+
+            fn $fn(self) -> Result<T::Output, ArithmeticError> {
+                match self {
+                    Err(err) => Err(err.into()),
+                    Ok(value) => value.$fn().map_err(Into::into),
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements checked binary operators for `Result<T, E>`.
+macro_rules! impl_result_try_binary_ops {
+    { $(impl $trait:ident::$fn:ident;)* } => { $(
+        impl<T, E, Rhs> $trait<Rhs> for Result<T, E>
+        where
+            T: $trait<Rhs>,
+            T::Error: Into<ArithmeticError>,
+            E: Into<ArithmeticError>,
+        {
+            type Output = T::Output;
+            type Error = ArithmeticError;
+
+            fn $fn(self, rhs: Rhs) -> Result<T::Output, ArithmeticError> {
+                match self {
+                    Err(err) => Err(err.into()),
+                    Ok(lhs) => lhs.$fn(rhs).map_err(Into::into),
+                }
+            }
+        }
+    )* };
+}
+
+impl_option_try_unary_ops! {
+    impl TryNeg::try_neg;
+}
+
+impl_option_try_binary_ops! {
+    impl TryAdd::try_add;
+    impl TryDiv::try_div;
+    impl TryMul::try_mul;
+    impl TryRem::try_rem;
+    impl TrySub::try_sub;
+}
+
+impl_option_unary_ops! {
+    impl SaturatingNeg::saturating_neg;
+    impl WrappingNeg::wrapping_neg;
+}
+
+impl_option_binary_ops! {
+    impl SaturatingAdd::saturating_add;
+    impl SaturatingMul::saturating_mul;
+    impl SaturatingSub::saturating_sub;
+    impl WrappingAdd::wrapping_add;
+    impl WrappingMul::wrapping_mul;
+    impl WrappingSub::wrapping_sub;
+}
+
+impl_result_try_unary_ops! {
+    impl TryNeg::try_neg;
+}
+
+impl_result_try_binary_ops! {
+    impl TryAdd::try_add;
+    impl TryDiv::try_div;
+    impl TryMul::try_mul;
+    impl TryRem::try_rem;
+    impl TrySub::try_sub;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_option_try_ops() {
+    use crate::error::{Overflow, RangeError};
+
+    assert_eq!(Some(100u8).try_add(Some(55u8)), Ok(Some(155)));
+    assert_eq!(Some(200u8).try_add(Some(56u8)), Err(Overflow));
+    assert_eq!(None::<u8>.try_add(Some(56u8)), Ok(None::<u8>));
+    assert_eq!(Some(200u8).try_add(None::<u8>), Ok(None));
+    assert_eq!(Some(-128i8).try_neg(), Err(Overflow));
+    assert_eq!(None::<i8>.try_neg(), Ok(None));
+    assert_eq!(Some(16i8).try_mul(Some(-16i8)), Err(RangeError::Underflow));
+}
+
+#[test]
+fn test_option_saturating_wrapping_ops() {
+    assert_eq!(SaturatingAdd::saturating_add(Some(200u8), Some(56u8)), Some(255));
+    assert_eq!(SaturatingAdd::saturating_add(Some(200u8), None::<u8>), None);
+    assert_eq!(SaturatingNeg::saturating_neg(Some(-128i8)), Some(127));
+    assert_eq!(WrappingAdd::wrapping_add(Some(200u8), Some(56u8)), Some(0));
+    assert_eq!(WrappingNeg::wrapping_neg(None::<u8>), None);
+}
+
+#[test]
+fn test_result_try_ops() {
+    use core::num::Wrapping;
+
+    assert_eq!(100u8.try_add(20u8).try_mul(2u8), Ok(240));
+    assert_eq!(200u8.try_add(56u8).try_mul(2u8), Err(ArithmeticError::Overflow));
+    assert_eq!(100u8.try_mul(3u8).try_sub(1u8), Err(ArithmeticError::Overflow));
+    assert_eq!(1u8.try_sub(2u8).try_add(1u8), Err(ArithmeticError::Underflow));
+    assert_eq!(10i8.try_sub(20i8).try_div(0i8), Err(ArithmeticError::Undefined));
+    assert_eq!(127i8.try_add(1i8).try_neg(), Err(ArithmeticError::Overflow));
+    assert_eq!(Ok::<i8, ArithmeticError>(-127).try_sub(1i8).try_neg(),
+               Err(ArithmeticError::Overflow));
+    assert_eq!(Wrapping(255u8).try_add(Wrapping(1u8)).try_div(Wrapping(0u8)),
+               Err(ArithmeticError::Undefined));
+}
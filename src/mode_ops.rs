@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::ArithmeticError;
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::strict_ops::{StrictAdd, StrictMul, StrictNeg, StrictSub};
+use crate::try_ops::{TryAdd, TryMul, TryNeg, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+/// Behavior of an arithmetic operator in the event of an overflow or underflow, selected at
+/// runtime.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OverflowMode {
+    /// Returns an error.
+    Checked,
+    /// Returns the closest possible value.
+    Saturating,
+    /// Wraps around.
+    Wrapping,
+    /// Panics.
+    Panicking,
+}
+
+/// Addition operator whose overflow behavior is selected at runtime.
+pub trait ModalAdd<Rhs = Self> {
+    type Output;
+    fn modal_add(self, rhs: Rhs, mode: OverflowMode) -> Result<Self::Output, ArithmeticError>;
+}
+
+/// Multiplication operator whose overflow behavior is selected at runtime.
+pub trait ModalMul<Rhs = Self> {
+    type Output;
+    fn modal_mul(self, rhs: Rhs, mode: OverflowMode) -> Result<Self::Output, ArithmeticError>;
+}
+
+/// Negation operator whose overflow behavior is selected at runtime.
+pub trait ModalNeg {
+    type Output;
+    fn modal_neg(self, mode: OverflowMode) -> Result<Self::Output, ArithmeticError>;
+}
+
+/// Subtraction operator whose overflow behavior is selected at runtime.
+pub trait ModalSub<Rhs = Self> {
+    type Output;
+    fn modal_sub(self, rhs: Rhs, mode: OverflowMode) -> Result<Self::Output, ArithmeticError>;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+// Modal operators are implemented for every type which implements the checked, saturating,
+// wrapping and strict forms of the operator. Only the checked mode returns an error; the panicking
+// mode uses the strict operator.
+
+/// Implements binary modal operators.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident => $try_trait:ident::$try_fn:ident,
+        $saturating_trait:ident::$saturating_fn:ident,
+        $wrapping_trait:ident::$wrapping_fn:ident,
+        $strict_trait:ident::$strict_fn:ident;)* } => { $(
+        impl<T, R> $trait<R> for T
+        where
+            T: $try_trait<R>,
+            T: $saturating_trait<R, Output = <T as $try_trait<R>>::Output>,
+            T: $wrapping_trait<R, Output = <T as $try_trait<R>>::Output>,
+            T: $strict_trait<R, Output = <T as $try_trait<R>>::Output>,
+            <T as $try_trait<R>>::Error: Into<ArithmeticError>,
+        {
+            type Output = <T as $try_trait<R>>::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: R, mode: OverflowMode) -> Result<Self::Output, ArithmeticError> {
+                match mode {
+                    OverflowMode::Checked => $try_trait::$try_fn(self, rhs).map_err(Into::into),
+                    OverflowMode::Saturating => Ok($saturating_trait::$saturating_fn(self, rhs)),
+                    OverflowMode::Wrapping => Ok($wrapping_trait::$wrapping_fn(self, rhs)),
+                    OverflowMode::Panicking => Ok($strict_trait::$strict_fn(self, rhs)),
+                }
+            }
+        }
+    )* };
+}
+
+impl_binary_ops! {
+    impl ModalAdd::modal_add => TryAdd::try_add, SaturatingAdd::saturating_add,
+        WrappingAdd::wrapping_add, StrictAdd::strict_add;
+    impl ModalMul::modal_mul => TryMul::try_mul, SaturatingMul::saturating_mul,
+        WrappingMul::wrapping_mul, StrictMul::strict_mul;
+    impl ModalSub::modal_sub => TrySub::try_sub, SaturatingSub::saturating_sub,
+        WrappingSub::wrapping_sub, StrictSub::strict_sub;
+}
+
+impl<T> ModalNeg for T
+where
+    T: TryNeg,
+    T: SaturatingNeg<Output = <T as TryNeg>::Output>,
+    T: WrappingNeg<Output = <T as TryNeg>::Output>,
+    T: StrictNeg<Output = <T as TryNeg>::Output>,
+    <T as TryNeg>::Error: Into<ArithmeticError>,
+{
+    type Output = <T as TryNeg>::Output;
+
+    #[track_caller]
+    fn modal_neg(self, mode: OverflowMode) -> Result<Self::Output, ArithmeticError> {
+        match mode {
+            OverflowMode::Checked => self.try_neg().map_err(Into::into),
+            OverflowMode::Saturating => Ok(self.saturating_neg()),
+            OverflowMode::Wrapping => Ok(self.wrapping_neg()),
+            OverflowMode::Panicking => Ok(StrictNeg::strict_neg(self)),
+        }
+    }
+}
+
+// Unsigned integers don't implement `SaturatingNeg`, so their negation is implemented directly.
+// The saturating mode returns zero, which is the closest possible value to any negated value.
+
+/// Implements modal negation for unsigned integer types.
+macro_rules! impl_uint_neg {
+    ($($ty:ident),*) => { $(
+        impl ModalNeg for $ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn modal_neg(self, mode: OverflowMode) -> Result<$ty, ArithmeticError> {
+                match mode {
+                    OverflowMode::Checked => self.try_neg().map_err(Into::into),
+                    OverflowMode::Saturating => Ok(0),
+                    OverflowMode::Wrapping => Ok(self.wrapping_neg()),
+                    OverflowMode::Panicking => Ok(StrictNeg::strict_neg(self)),
+                }
+            }
+        }
+
+        impl<'a> ModalNeg for &'a $ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn modal_neg(self, mode: OverflowMode) -> Result<$ty, ArithmeticError> {
+                (*self).modal_neg(mode)
+            }
+        }
+    )* };
+}
+
+impl_uint_neg!(u8, u16, u32, u64, u128, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_modal_ops() {
+    use OverflowMode::*;
+
+    assert_eq!(200u8.modal_add(100, Checked), Err(ArithmeticError::Overflow));
+    assert_eq!(200u8.modal_add(100, Saturating), Ok(255));
+    assert_eq!(200u8.modal_add(100, Wrapping), Ok(44));
+    assert_eq!(200u8.modal_add(50, Panicking), Ok(250));
+    assert_eq!((&1u8).modal_sub(&2, Checked), Err(ArithmeticError::Underflow));
+    assert_eq!(1u8.modal_sub(2, Saturating), Ok(0));
+    assert_eq!((-100i8).modal_mul(2, Checked), Err(ArithmeticError::Underflow));
+    assert_eq!((-100i8).modal_mul(2, Wrapping), Ok(56));
+    assert_eq!(i32::MIN.modal_neg(Checked), Err(ArithmeticError::Overflow));
+    assert_eq!(i32::MIN.modal_neg(Saturating), Ok(i32::MAX));
+    assert_eq!(i32::MIN.modal_neg(Wrapping), Ok(i32::MIN));
+    assert_eq!(5u32.modal_neg(Checked), Err(ArithmeticError::Underflow));
+    assert_eq!((&5u32).modal_neg(Saturating), Ok(0));
+    assert_eq!(5u32.modal_neg(Wrapping), Ok(u32::MAX - 4));
+    assert_eq!(0u32.modal_neg(Panicking), Ok(0));
+}
+
+#[test]
+#[should_panic(expected = "arithmetic underflow")]
+fn test_modal_panicking() {
+    let _ = 0u64.modal_sub(1, OverflowMode::Panicking);
+}
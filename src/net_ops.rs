@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::error::{Overflow, Underflow};
+use crate::saturating_ops::{SaturatingAdd, SaturatingSub};
+use crate::try_ops::{TryAdd, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingSub};
+
+/// Implements checked binary operators for reference types.
+macro_rules! impl_try_ref_ops {
+    { $(impl $trait:ident<$rhs:ident>::$fn:ident for $ty:ident;)* } => { $(
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements saturating or wrapping binary operators by applying them to the integer
+/// representation of the address.
+macro_rules! impl_binary_ops {
+    {
+        $(impl $trait:ident<$rhs:ident>::$fn:ident for $ty:ident($bits:ident) -> $output:ident;)*
+    } => { $(
+        impl $trait<$rhs> for $ty {
+            type Output = $output;
+
+            fn $fn(self, rhs: $rhs) -> $output {
+                $output::from($bits::from(self).$fn($bits::from(rhs)))
+            }
+        }
+
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = $output;
+
+            fn $fn(self, rhs: $rhs) -> $output {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = $output;
+
+            fn $fn(self, rhs: &'r $rhs) -> $output {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = $output;
+
+            fn $fn(self, rhs: &'r $rhs) -> $output {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements operators for IP address types.
+///
+/// Addresses are treated as their integer representation, so the address space is bounded by the
+/// unspecified address and the broadcast (or all-ones) address.
+macro_rules! impl_addr_ops {
+    ($($ty:ident: $bits:ident),*) => { $(
+        impl TryAdd<$bits> for $ty {
+            type Output = $ty;
+            type Error = Overflow;
+
+            fn try_add(self, rhs: $bits) -> Result<$ty, Overflow> {
+                match $bits::from(self).checked_add(rhs) {
+                    None => Err(Overflow),
+                    Some(n) => Ok($ty::from(n)),
+                }
+            }
+        }
+
+        impl TrySub<$bits> for $ty {
+            type Output = $ty;
+            type Error = Underflow;
+
+            fn try_sub(self, rhs: $bits) -> Result<$ty, Underflow> {
+                match $bits::from(self).checked_sub(rhs) {
+                    None => Err(Underflow),
+                    Some(n) => Ok($ty::from(n)),
+                }
+            }
+        }
+
+        /// Returns the number of addresses from `rhs` to `self`, or [Underflow] if `rhs` is
+        /// greater than `self`.
+        impl TrySub for $ty {
+            type Output = $bits;
+            type Error = Underflow;
+
+            fn try_sub(self, rhs: $ty) -> Result<$bits, Underflow> {
+                match $bits::from(self).checked_sub($bits::from(rhs)) {
+                    None => Err(Underflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl_try_ref_ops! {
+            impl TryAdd<$bits>::try_add for $ty;
+            impl TrySub<$bits>::try_sub for $ty;
+            impl TrySub<$ty>::try_sub for $ty;
+        }
+
+        impl_binary_ops! {
+            impl SaturatingAdd<$bits>::saturating_add for $ty($bits) -> $ty;
+            impl SaturatingSub<$bits>::saturating_sub for $ty($bits) -> $ty;
+            impl SaturatingSub<$ty>::saturating_sub for $ty($bits) -> $bits;
+            impl WrappingAdd<$bits>::wrapping_add for $ty($bits) -> $ty;
+            impl WrappingSub<$bits>::wrapping_sub for $ty($bits) -> $ty;
+            impl WrappingSub<$ty>::wrapping_sub for $ty($bits) -> $bits;
+        }
+    )* };
+}
+
+impl_addr_ops!(Ipv4Addr: u32, Ipv6Addr: u128);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_ipv4_try_ops() {
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 255).try_add(1), Ok(Ipv4Addr::new(10, 0, 1, 0)));
+    assert_eq!(Ipv4Addr::BROADCAST.try_add(1), Err(Overflow));
+    assert_eq!(Ipv4Addr::new(10, 0, 1, 0).try_sub(1), Ok(Ipv4Addr::new(10, 0, 0, 255)));
+    assert_eq!(Ipv4Addr::UNSPECIFIED.try_sub(1), Err(Underflow));
+    assert_eq!(Ipv4Addr::new(10, 0, 1, 0).try_sub(Ipv4Addr::new(10, 0, 0, 0)), Ok(256));
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 0).try_sub(Ipv4Addr::new(10, 0, 1, 0)), Err(Underflow));
+}
+
+#[test]
+fn test_ipv6_try_ops() {
+    assert_eq!(Ipv6Addr::LOCALHOST.try_add(1), Ok(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 2)));
+    assert_eq!(Ipv6Addr::from(u128::MAX).try_add(1), Err(Overflow));
+    assert_eq!(Ipv6Addr::LOCALHOST.try_sub(1), Ok(Ipv6Addr::UNSPECIFIED));
+    assert_eq!(Ipv6Addr::UNSPECIFIED.try_sub(1), Err(Underflow));
+    assert_eq!(Ipv6Addr::from(u128::MAX).try_sub(Ipv6Addr::UNSPECIFIED), Ok(u128::MAX));
+    assert_eq!(Ipv6Addr::UNSPECIFIED.try_sub(Ipv6Addr::LOCALHOST), Err(Underflow));
+}
+
+#[test]
+fn test_addr_saturating_ops() {
+    assert_eq!(SaturatingAdd::saturating_add(Ipv4Addr::BROADCAST, 1), Ipv4Addr::BROADCAST);
+    assert_eq!(SaturatingSub::saturating_sub(Ipv4Addr::UNSPECIFIED, 1), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(SaturatingSub::saturating_sub(Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST), 0);
+    assert_eq!(SaturatingSub::saturating_sub(Ipv6Addr::UNSPECIFIED, 1), Ipv6Addr::UNSPECIFIED);
+}
+
+#[test]
+fn test_addr_wrapping_ops() {
+    assert_eq!(WrappingAdd::wrapping_add(Ipv4Addr::BROADCAST, 1), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(WrappingSub::wrapping_sub(Ipv4Addr::UNSPECIFIED, 1), Ipv4Addr::BROADCAST);
+    assert_eq!(WrappingSub::wrapping_sub(Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST), 1);
+    assert_eq!(WrappingAdd::wrapping_add(Ipv6Addr::from(u128::MAX), 1), Ipv6Addr::UNSPECIFIED);
+}
@@ -0,0 +1,240 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+/// Implements every checked, saturating and wrapping operator trait for a newtype by delegating to
+/// its inner field.
+///
+/// Each newtype is given as `Name(Inner)`, where `Name` is a tuple struct whose only field has type
+/// `Inner`, e.g. `impl_ext_ops_newtype!(Meters(u64), Offset(i32));`. Binary operators are
+/// implemented with `Name` or `&Name` on either side and unary operators for `Name` and `&Name`.
+/// The error types are those of the corresponding operators on `Inner`.
+///
+/// Each impl is bounded on the corresponding impl for `Inner`, so operators which `Inner` does not
+/// implement (such as `SaturatingNeg` for unsigned integers) are not available for `Name` either.
+/// The by-reference forms delegate to the by-reference forms for `Inner`, so `Name` does not need
+/// to implement `Copy`.
+#[macro_export]
+macro_rules! impl_ext_ops_newtype {
+    ($($name:ident($inner:ty)),* $(,)?) => { $(
+        $crate::__impl_newtype_ops! {
+            $name($inner);
+            try binary TryAdd::try_add;
+            try binary TryDiv::try_div;
+            try binary TryMul::try_mul;
+            try unary TryNeg::try_neg;
+            try binary TryRem::try_rem;
+            try binary TrySub::try_sub;
+            infallible binary SaturatingAdd::saturating_add;
+            infallible binary SaturatingMul::saturating_mul;
+            infallible unary SaturatingNeg::saturating_neg;
+            infallible binary SaturatingSub::saturating_sub;
+            infallible binary WrappingAdd::wrapping_add;
+            infallible binary WrappingMul::wrapping_mul;
+            infallible unary WrappingNeg::wrapping_neg;
+            infallible binary WrappingSub::wrapping_sub;
+        }
+    )* };
+}
+
+/// Implementation of `impl_ext_ops_newtype!`.
+///
+/// Impls without lifetime parameters use a `for<'x>` bound so the bound is not trivial, which would
+/// otherwise be an error when the inner type does not implement the trait.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_ops {
+    { $name:ident($inner:ty); $($kind:ident $arity:ident $trait:ident::$fn:ident;)* } => { $(
+        $crate::__impl_newtype_ops!(@$kind $arity $name($inner) $trait::$fn);
+    )* };
+
+    (@try unary $name:ident($inner:ty) $trait:ident::$fn:ident) => {
+        impl $crate::$trait for $name
+        where
+            for<'x> $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <$inner as $crate::$trait>::Error;
+
+            fn $fn(self) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(self.0).map($name)
+            }
+        }
+
+        impl<'a> $crate::$trait for &'a $name
+        where
+            &'a $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <&'a $inner as $crate::$trait>::Error;
+
+            fn $fn(self) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(&self.0).map($name)
+            }
+        }
+    };
+
+    (@try binary $name:ident($inner:ty) $trait:ident::$fn:ident) => {
+        impl $crate::$trait for $name
+        where
+            for<'x> $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <$inner as $crate::$trait>::Error;
+
+            fn $fn(self, rhs: $name) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(self.0, rhs.0).map($name)
+            }
+        }
+
+        impl<'a> $crate::$trait<$name> for &'a $name
+        where
+            &'a $inner: $crate::$trait<$inner, Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <&'a $inner as $crate::$trait<$inner>>::Error;
+
+            fn $fn(self, rhs: $name) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(&self.0, rhs.0).map($name)
+            }
+        }
+
+        impl<'r> $crate::$trait<&'r $name> for $name
+        where
+            $inner: $crate::$trait<&'r $inner, Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <$inner as $crate::$trait<&'r $inner>>::Error;
+
+            fn $fn(self, rhs: &'r $name) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(self.0, &rhs.0).map($name)
+            }
+        }
+
+        impl<'a, 'r> $crate::$trait<&'r $name> for &'a $name
+        where
+            &'a $inner: $crate::$trait<&'r $inner, Output = $inner>,
+        {
+            type Output = $name;
+            type Error = <&'a $inner as $crate::$trait<&'r $inner>>::Error;
+
+            fn $fn(self, rhs: &'r $name) -> ::core::result::Result<$name, Self::Error> {
+                $crate::$trait::$fn(&self.0, &rhs.0).map($name)
+            }
+        }
+    };
+
+    (@infallible unary $name:ident($inner:ty) $trait:ident::$fn:ident) => {
+        impl $crate::$trait for $name
+        where
+            for<'x> $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self) -> $name {
+                $name($crate::$trait::$fn(self.0))
+            }
+        }
+
+        impl<'a> $crate::$trait for &'a $name
+        where
+            &'a $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self) -> $name {
+                $name($crate::$trait::$fn(&self.0))
+            }
+        }
+    };
+
+    (@infallible binary $name:ident($inner:ty) $trait:ident::$fn:ident) => {
+        impl $crate::$trait for $name
+        where
+            for<'x> $inner: $crate::$trait<Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self, rhs: $name) -> $name {
+                $name($crate::$trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $crate::$trait<$name> for &'a $name
+        where
+            &'a $inner: $crate::$trait<$inner, Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self, rhs: $name) -> $name {
+                $name($crate::$trait::$fn(&self.0, rhs.0))
+            }
+        }
+
+        impl<'r> $crate::$trait<&'r $name> for $name
+        where
+            $inner: $crate::$trait<&'r $inner, Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self, rhs: &'r $name) -> $name {
+                $name($crate::$trait::$fn(self.0, &rhs.0))
+            }
+        }
+
+        impl<'a, 'r> $crate::$trait<&'r $name> for &'a $name
+        where
+            &'a $inner: $crate::$trait<&'r $inner, Output = $inner>,
+        {
+            type Output = $name;
+
+            fn $fn(self, rhs: &'r $name) -> $name {
+                $name($crate::$trait::$fn(&self.0, &rhs.0))
+            }
+        }
+    };
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Overflow, RangeError, Undefined, Underflow};
+    use crate::saturating_ops::{SaturatingAdd, SaturatingNeg, SaturatingSub};
+    use crate::try_ops::{TryAdd, TryDiv, TryNeg, TryRem, TrySub};
+    use crate::wrapping_ops::{WrappingMul, WrappingNeg};
+
+    #[derive(Debug, PartialEq)]
+    struct Meters(u64);
+
+    #[derive(Debug, PartialEq)]
+    struct Offset(i8);
+
+    impl_ext_ops_newtype!(Meters(u64), Offset(i8));
+
+    #[test]
+    fn test_newtype_try_ops() {
+        assert_eq!(Meters(1).try_add(Meters(2)), Ok(Meters(3)));
+        assert_eq!(Meters(u64::MAX).try_add(&Meters(1)), Err(Overflow));
+        assert_eq!((&Meters(1)).try_sub(Meters(2)), Err(Underflow));
+        assert_eq!((&Meters(7)).try_rem(&Meters(4)), Ok(Meters(3)));
+        assert_eq!(Meters(7).try_div(Meters(0)), Err(Undefined));
+        assert_eq!(Offset(-128).try_neg(), Err(Overflow));
+        assert_eq!((&Offset(5)).try_neg(), Ok(Offset(-5)));
+        assert_eq!(Offset(100).try_add(&Offset(100)), Err(RangeError::Overflow));
+    }
+
+    #[test]
+    fn test_newtype_saturating_wrapping_ops() {
+        assert_eq!(Meters(u64::MAX).saturating_add(Meters(1)), Meters(u64::MAX));
+        assert_eq!((&Meters(1)).saturating_sub(&Meters(2)), Meters(0));
+        assert_eq!(Offset(-128).saturating_neg(), Offset(127));
+        assert_eq!((&Offset(-128)).wrapping_neg(), Offset(-128));
+        assert_eq!(Offset(64).wrapping_mul(&Offset(2)), Offset(-128));
+        assert_eq!((&Meters(u64::MAX)).wrapping_mul(Meters(2)), Meters(u64::MAX - 1));
+    }
+}
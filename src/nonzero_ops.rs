@@ -0,0 +1,330 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::convert::Infallible;
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+use crate::error::{Overflow, RangeError};
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem};
+
+/// Implements checked unary operators for reference types.
+macro_rules! impl_unary_ref_ops {
+    { $(impl $trait:ident::$fn:ident for $ty:ident;)* } => { $(
+        impl<'a> $trait for &'a $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self) -> Result<$ty, Self::Error> {
+                $trait::$fn(*self)
+            }
+        }
+    )* };
+}
+
+/// Implements checked binary operators for reference types.
+macro_rules! impl_binary_ref_ops {
+    { $(impl $trait:ident<$rhs:ident>::$fn:ident for $ty:ident;)* } => { $(
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements saturating binary operators.
+macro_rules! impl_saturating_binary_ops {
+    { $(impl $trait:ident::$fn:ident for $ty:ident;)* } => { $(
+        impl $trait for $ty {
+            type Output = $ty;
+
+            fn $fn(self, rhs: $ty) -> $ty {
+                self.$fn(rhs)
+            }
+        }
+
+        impl<'a> $trait<$ty> for &'a $ty {
+            type Output = $ty;
+
+            fn $fn(self, rhs: $ty) -> $ty {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $ty> for $ty {
+            type Output = $ty;
+
+            fn $fn(self, rhs: &'r $ty) -> $ty {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $ty> for &'a $ty {
+            type Output = $ty;
+
+            fn $fn(self, rhs: &'r $ty) -> $ty {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements operators for signed non-zero integer types.
+///
+/// Addition and subtraction are not implemented because their results may be zero.
+macro_rules! impl_int_ops {
+    ($($ty:ident: $prim:ident),*) => { $(
+        impl TryMul for $ty {
+            type Output = $ty;
+            type Error = RangeError;
+
+            fn try_mul(self, rhs: $ty) -> Result<$ty, RangeError> {
+                match self.checked_mul(rhs) {
+                    None => Err(if self.is_negative() == rhs.is_negative() {
+                        RangeError::Overflow
+                    } else {
+                        RangeError::Underflow
+                    }),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl TryNeg for $ty {
+            type Output = $ty;
+            type Error = Overflow;
+
+            fn try_neg(self) -> Result<$ty, Overflow> {
+                match self.checked_neg() {
+                    None => Err(Overflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl SaturatingNeg for $ty {
+            type Output = $ty;
+
+            fn saturating_neg(self) -> $ty {
+                self.saturating_neg()
+            }
+        }
+
+        impl<'a> SaturatingNeg for &'a $ty {
+            type Output = $ty;
+
+            fn saturating_neg(self) -> $ty {
+                SaturatingNeg::saturating_neg(*self)
+            }
+        }
+
+        impl TryDiv<$ty> for $prim {
+            type Output = $prim;
+            type Error = Overflow;
+
+            fn try_div(self, rhs: $ty) -> Result<$prim, Overflow> {
+                match self.checked_div(rhs.get()) {
+                    // Only reachable if self == $prim::MIN && rhs == -1.
+                    None => Err(Overflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl TryRem<$ty> for $prim {
+            type Output = $prim;
+            type Error = Infallible;
+
+            fn try_rem(self, rhs: $ty) -> Result<$prim, Infallible> {
+                // Only fails if self == $prim::MIN && rhs == -1, in which case the remainder is
+                // known to be zero.
+                Ok(self.checked_rem(rhs.get()).unwrap_or(0))
+            }
+        }
+
+        impl_unary_ref_ops! {
+            impl TryNeg::try_neg for $ty;
+        }
+
+        impl_binary_ref_ops! {
+            impl TryMul<$ty>::try_mul for $ty;
+            impl TryDiv<$ty>::try_div for $prim;
+            impl TryRem<$ty>::try_rem for $prim;
+        }
+
+        impl_saturating_binary_ops! {
+            impl SaturatingMul::saturating_mul for $ty;
+        }
+    )* };
+}
+
+impl_int_ops! {
+    NonZeroI8: i8, NonZeroI16: i16, NonZeroI32: i32, NonZeroI64: i64, NonZeroI128: i128,
+    NonZeroIsize: isize
+}
+
+/// Implements operators for unsigned non-zero integer types.
+macro_rules! impl_uint_ops {
+    ($($ty:ident: $prim:ident),*) => { $(
+        impl TryAdd for $ty {
+            type Output = $ty;
+            type Error = Overflow;
+
+            fn try_add(self, rhs: $ty) -> Result<$ty, Overflow> {
+                match self.checked_add(rhs.get()) {
+                    None => Err(Overflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl TryAdd<$prim> for $ty {
+            type Output = $ty;
+            type Error = Overflow;
+
+            fn try_add(self, rhs: $prim) -> Result<$ty, Overflow> {
+                match self.checked_add(rhs) {
+                    None => Err(Overflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl TryMul for $ty {
+            type Output = $ty;
+            type Error = Overflow;
+
+            fn try_mul(self, rhs: $ty) -> Result<$ty, Overflow> {
+                match self.checked_mul(rhs) {
+                    None => Err(Overflow),
+                    Some(n) => Ok(n),
+                }
+            }
+        }
+
+        impl TryDiv<$ty> for $prim {
+            type Output = $prim;
+            type Error = Infallible;
+
+            fn try_div(self, rhs: $ty) -> Result<$prim, Infallible> {
+                Ok(self / rhs)
+            }
+        }
+
+        impl TryRem<$ty> for $prim {
+            type Output = $prim;
+            type Error = Infallible;
+
+            fn try_rem(self, rhs: $ty) -> Result<$prim, Infallible> {
+                Ok(self % rhs)
+            }
+        }
+
+        impl SaturatingAdd for $ty {
+            type Output = $ty;
+
+            fn saturating_add(self, rhs: $ty) -> $ty {
+                self.saturating_add(rhs.get())
+            }
+        }
+
+        impl SaturatingAdd<$prim> for $ty {
+            type Output = $ty;
+
+            fn saturating_add(self, rhs: $prim) -> $ty {
+                self.saturating_add(rhs)
+            }
+        }
+
+        impl_binary_ref_ops! {
+            impl TryAdd<$ty>::try_add for $ty;
+            impl TryAdd<$prim>::try_add for $ty;
+            impl TryMul<$ty>::try_mul for $ty;
+            impl TryDiv<$ty>::try_div for $prim;
+            impl TryRem<$ty>::try_rem for $prim;
+        }
+
+        impl_saturating_binary_ops! {
+            impl SaturatingMul::saturating_mul for $ty;
+        }
+    )* };
+}
+
+impl_uint_ops! {
+    NonZeroU8: u8, NonZeroU16: u16, NonZeroU32: u32, NonZeroU64: u64, NonZeroU128: u128,
+    NonZeroUsize: usize
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_nonzero_try_add() {
+    let nz = |n| NonZeroU8::new(n).unwrap();
+    assert_eq!(NonZeroU8::try_add(nz(200), nz(55)), Ok(nz(255)));
+    assert_eq!(NonZeroU8::try_add(nz(200), nz(56)), Err(Overflow));
+    assert_eq!(NonZeroU8::try_add(nz(200), 0u8), Ok(nz(200)));
+    assert_eq!(NonZeroU8::try_add(nz(255), 1u8), Err(Overflow));
+}
+
+#[test]
+fn test_nonzero_try_mul() {
+    let nz = |n| NonZeroU8::new(n).unwrap();
+    assert_eq!(NonZeroU8::try_mul(nz(85), nz(3)), Ok(nz(255)));
+    assert_eq!(NonZeroU8::try_mul(nz(16), nz(16)), Err(Overflow));
+    let nz = |n| NonZeroI8::new(n).unwrap();
+    assert_eq!(NonZeroI8::try_mul(nz(16), nz(-8)), Ok(nz(-128)));
+    assert_eq!(NonZeroI8::try_mul(nz(16), nz(8)), Err(RangeError::Overflow));
+    assert_eq!(NonZeroI8::try_mul(nz(43), nz(-3)), Err(RangeError::Underflow));
+    assert_eq!(NonZeroI8::try_mul(nz(-128), nz(-1)), Err(RangeError::Overflow));
+}
+
+#[test]
+fn test_nonzero_try_neg() {
+    let nz = |n| NonZeroI8::new(n).unwrap();
+    assert_eq!(NonZeroI8::try_neg(nz(127)), Ok(nz(-127)));
+    assert_eq!(NonZeroI8::try_neg(nz(-128)), Err(Overflow));
+}
+
+#[test]
+fn test_nonzero_try_div() {
+    assert_eq!(u8::try_div(100, NonZeroU8::new(10).unwrap()), Ok(10));
+    assert_eq!(i8::try_div(-100, NonZeroI8::new(10).unwrap()), Ok(-10));
+    assert_eq!(i8::try_div(-128, NonZeroI8::new(-1).unwrap()), Err(Overflow));
+}
+
+#[test]
+fn test_nonzero_try_rem() {
+    assert_eq!(u8::try_rem(99, NonZeroU8::new(10).unwrap()), Ok(9));
+    assert_eq!(i8::try_rem(-99, NonZeroI8::new(10).unwrap()), Ok(-9));
+    assert_eq!(i8::try_rem(-128, NonZeroI8::new(-1).unwrap()), Ok(0));
+}
@@ -0,0 +1,340 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::iter::{self, Sum};
+
+use rayon::iter::ParallelIterator;
+
+use crate::error::{Overflow, RangeError};
+use crate::saturating_ops::SaturatingAdd;
+use crate::try_ops::{TryAdd, TryMul};
+use crate::wrapping_ops::WrappingAdd;
+
+// Checked and saturating sums are not associative, so they can't simply be split between threads.
+// Instead, each part of the iterator is reduced to a summary in a 128-bit accumulator which can be
+// combined with other summaries in any grouping, and which determines the outcome of the equivalent
+// sequential operation exactly.
+//
+// The accumulators can't overflow unless the iterator yields at least 2^63 items.
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Integer types which can be reduced in parallel by [ExtOpsParallelIteratorExt].
+///
+/// This is implemented for all primitive integer types except `i128` and `u128`, and can't be
+/// implemented outside of this crate.
+pub trait ParallelReduce:
+    Copy
+    + Send
+    + SaturatingAdd<Output = Self>
+    + TryAdd<Output = Self>
+    + TryMul<Output = Self, Error = <Self as TryAdd>::Error>
+    + private::Sealed
+{
+    #[doc(hidden)]
+    const MIN_WIDE: i128;
+    #[doc(hidden)]
+    const MAX_WIDE: i128;
+
+    #[doc(hidden)]
+    fn to_wide(self) -> i128;
+    /// Converts a value which is known to be in range.
+    #[doc(hidden)]
+    fn from_wide(wide: i128) -> Self;
+    #[doc(hidden)]
+    fn range_error(overflow: bool) -> <Self as TryAdd>::Error;
+}
+
+/// Extension methods for summing or multiplying the items of a [ParallelIterator] with ext-ops
+/// operators.
+///
+/// Checked and saturating operations produce the same result as the equivalent sequential
+/// operations in [ExtOpsIteratorExt][crate::ExtOpsIteratorExt], regardless of how the items are
+/// divided between threads. In particular, a checked operation fails if any partial result of the
+/// sequential operation would be out of range, even if the final result is not.
+///
+/// Unlike the sequential operations, checked operations don't report the index of the item at which
+/// the operation failed. If the partial results exceed both the maximum and the minimum of the item
+/// type, the error indicates overflow.
+pub trait ExtOpsParallelIteratorExt: ParallelIterator {
+    fn try_sum(self) -> Result<Self::Item, <Self::Item as TryAdd>::Error>
+    where
+        Self::Item: ParallelReduce,
+    {
+        self.map(|n| SumRange::new(n.to_wide()))
+            .reduce(SumRange::default, SumRange::then)
+            .try_into_item()
+    }
+
+    fn try_product(self) -> Result<Self::Item, <Self::Item as TryAdd>::Error>
+    where
+        Self::Item: ParallelReduce,
+    {
+        self.map(|n| ProductRange::new(n.to_wide()))
+            .reduce(ProductRange::default, ProductRange::then)
+            .try_into_item()
+    }
+
+    fn saturating_sum(self) -> Self::Item
+    where
+        Self::Item: ParallelReduce,
+    {
+        self.map(SaturatingSum::new::<Self::Item>)
+            .reduce(SaturatingSum::default, SaturatingSum::then)
+            .into_item()
+    }
+
+    fn wrapping_sum(self) -> Self::Item
+    where
+        Self::Item: WrappingAdd<Output = Self::Item> + Sum,
+    {
+        self.reduce(|| iter::empty().sum(), WrappingAdd::wrapping_add)
+    }
+}
+
+impl<I: ParallelIterator> ExtOpsParallelIteratorExt for I {}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Summary of a checked sum: the total, and the range of the partial sums.
+///
+/// The empty partial sum is included in the range, which has no effect on the outcome since zero is
+/// always in range.
+#[derive(Default)]
+struct SumRange {
+    total: i128,
+    min: i128,
+    max: i128,
+}
+
+impl SumRange {
+    fn new(n: i128) -> SumRange {
+        SumRange { total: n, min: n.min(0), max: n.max(0) }
+    }
+
+    /// Combines the summary of a sum with the summary of the items which follow it.
+    fn then(self, next: SumRange) -> SumRange {
+        SumRange {
+            total: self.total + next.total,
+            min: self.min.min(self.total + next.min),
+            max: self.max.max(self.total + next.max),
+        }
+    }
+
+    fn try_into_item<T: ParallelReduce>(self) -> Result<T, <T as TryAdd>::Error> {
+        check_range::<T>(self.min, self.max)?;
+        Ok(T::from_wide(self.total))
+    }
+}
+
+/// Summary of a checked product: the total, and the range of the partial products.
+///
+/// Products are saturated at the bounds of `i128`. A saturated product is far outside the range of
+/// every supported item type, and keeps its sign when multiplied by other items, so saturation has
+/// no effect on the outcome. The empty partial product is included in the range, which has no
+/// effect on the outcome since one is always in range.
+struct ProductRange {
+    total: i128,
+    min: i128,
+    max: i128,
+}
+
+impl ProductRange {
+    fn new(n: i128) -> ProductRange {
+        ProductRange { total: n, min: n.min(1), max: n.max(1) }
+    }
+
+    /// Combines the summary of a product with the summary of the items which follow it.
+    fn then(self, next: ProductRange) -> ProductRange {
+        let (next_min, next_max) = match self.total < 0 {
+            false => (self.total.saturating_mul(next.min), self.total.saturating_mul(next.max)),
+            true => (self.total.saturating_mul(next.max), self.total.saturating_mul(next.min)),
+        };
+
+        ProductRange {
+            total: self.total.saturating_mul(next.total),
+            min: self.min.min(next_min),
+            max: self.max.max(next_max),
+        }
+    }
+
+    fn try_into_item<T: ParallelReduce>(self) -> Result<T, <T as TryAdd>::Error> {
+        check_range::<T>(self.min, self.max)?;
+        Ok(T::from_wide(self.total))
+    }
+}
+
+impl Default for ProductRange {
+    fn default() -> ProductRange {
+        ProductRange { total: 1, min: 1, max: 1 }
+    }
+}
+
+/// Summary of a saturating sum as a function of the value which precedes it, which has the form
+/// `|x| (x + offset).clamp(min, max)`.
+struct SaturatingSum {
+    offset: i128,
+    min: i128,
+    max: i128,
+}
+
+impl SaturatingSum {
+    fn new<T: ParallelReduce>(n: T) -> SaturatingSum {
+        SaturatingSum { offset: n.to_wide(), min: T::MIN_WIDE, max: T::MAX_WIDE }
+    }
+
+    /// Combines the summary of a sum with the summary of the items which follow it.
+    fn then(self, next: SaturatingSum) -> SaturatingSum {
+        SaturatingSum {
+            offset: self.offset + next.offset,
+            min: self.min.saturating_add(next.offset).clamp(next.min, next.max),
+            max: self.max.saturating_add(next.offset).clamp(next.min, next.max),
+        }
+    }
+
+    fn into_item<T: ParallelReduce>(self) -> T {
+        T::from_wide(self.offset.clamp(self.min, self.max))
+    }
+}
+
+impl Default for SaturatingSum {
+    fn default() -> SaturatingSum {
+        SaturatingSum { offset: 0, min: i128::MIN, max: i128::MAX }
+    }
+}
+
+/// Returns an error if a range of partial results is not contained in the range of `T`.
+fn check_range<T: ParallelReduce>(min: i128, max: i128) -> Result<(), <T as TryAdd>::Error> {
+    if max > T::MAX_WIDE {
+        Err(T::range_error(true))
+    } else if min < T::MIN_WIDE {
+        Err(T::range_error(false))
+    } else {
+        Ok(())
+    }
+}
+
+/// Implements `ParallelReduce` for primitive integer types.
+macro_rules! impl_parallel_reduce {
+    ($($ty:ident),* => |$overflow:ident| $error:expr) => { $(
+        impl private::Sealed for $ty {}
+
+        impl ParallelReduce for $ty {
+            const MIN_WIDE: i128 = $ty::MIN as i128;
+            const MAX_WIDE: i128 = $ty::MAX as i128;
+
+            fn to_wide(self) -> i128 {
+                self as i128
+            }
+
+            fn from_wide(wide: i128) -> $ty {
+                wide as $ty
+            }
+
+            fn range_error($overflow: bool) -> <$ty as TryAdd>::Error {
+                $error
+            }
+        }
+    )* };
+}
+
+impl_parallel_reduce!(i8, i16, i32, i64, isize => |overflow| match overflow {
+    true => RangeError::Overflow,
+    false => RangeError::Underflow,
+});
+impl_parallel_reduce!(u8, u16, u32, u64, usize => |_overflow| Overflow);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_par_try_sum() {
+    use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator};
+
+    use crate::ExtOpsIteratorExt;
+
+    let cases: [&[i8]; 6] = [
+        &[],
+        &[100, 27],
+        &[100, 28],
+        &[100, 27, -100, 100],
+        // The total is in range, but a partial sum overflows.
+        &[100, 100, -100],
+        &[-100, -28, 1, 100, -1, -100],
+    ];
+
+    for values in cases {
+        let expected = values.iter().copied().try_sum().map_err(|err| err.error);
+        assert_eq!(values.par_iter().copied().with_max_len(1).try_sum(), expected);
+        assert_eq!(values.par_iter().copied().try_sum(), expected);
+    }
+
+    let values: Vec<u64> = (0..100_000).collect();
+    assert_eq!(values.par_iter().copied().try_sum(), Ok(4_999_950_000));
+    let values: Vec<u32> = (0..100_000).map(|n| n % 17).collect();
+    assert_eq!(values.par_iter().copied().try_sum(), Ok(799_967));
+    assert_eq!(vec![u32::MAX, 1].par_iter().copied().try_sum(), Err(Overflow));
+}
+
+#[test]
+fn test_par_try_product() {
+    use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator};
+
+    use crate::ExtOpsIteratorExt;
+
+    let cases: [&[i8]; 7] = [
+        &[],
+        &[2, 3, -5],
+        &[-2, 64],
+        &[2, 64],
+        // The total is in range, but a partial product overflows.
+        &[-2, -64, -1],
+        &[4, 64, 0],
+        &[0, 64, 64],
+    ];
+
+    for values in cases {
+        let expected = values.iter().copied().try_product().map_err(|err| err.error);
+        assert_eq!(values.par_iter().copied().with_max_len(1).try_product(), expected);
+    }
+
+    let values: Vec<u64> = (1..=20).collect();
+    assert_eq!(values.par_iter().copied().try_product(), Ok(2_432_902_008_176_640_000));
+    let values: Vec<u64> = (1..=21).collect();
+    assert_eq!(values.par_iter().copied().try_product(), Err(Overflow));
+    let values = vec![u64::MAX; 10_000];
+    assert_eq!(values.par_iter().copied().try_product(), Err(Overflow));
+}
+
+#[test]
+fn test_par_saturating_wrapping_sum() {
+    use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator};
+
+    use crate::ExtOpsIteratorExt;
+
+    let cases: [&[i8]; 5] = [
+        &[],
+        &[100, 100, -100],
+        &[-100, -100, 50, 100, -1],
+        &[127, 127, -128, -128, -128, 5],
+        &[1, 2, 3],
+    ];
+
+    for values in cases {
+        let expected = values.iter().copied().saturating_sum();
+        assert_eq!(values.par_iter().copied().with_max_len(1).saturating_sum(), expected);
+        let expected = values.iter().copied().wrapping_sum();
+        assert_eq!(values.par_iter().copied().with_max_len(1).wrapping_sum(), expected);
+    }
+
+    let values: Vec<u8> = (0..10_000).map(|n| n as u8).collect();
+    assert_eq!(values.par_iter().copied().saturating_sum(), 255);
+    assert_eq!(values.par_iter().copied().wrapping_sum(),
+               values.iter().copied().wrapping_sum());
+}
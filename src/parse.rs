@@ -0,0 +1,178 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::{ParseIntError, RangeError};
+
+/// Checked conversion from a string or byte string to an integer.
+///
+/// The accepted syntax is an optional `+` or `-` sign, followed by an optional radix prefix (only
+/// when the radix is not given explicitly), followed by one or more digits. Digits may be separated
+/// by `_`, but the digit sequence may not begin or end with a separator.
+///
+/// Out-of-range values are reported as [ParseIntError::OutOfRange] with the direction in which the
+/// value exceeds the destination type. Negative values parsed into unsigned types are reported as
+/// underflows rather than as invalid digits. Syntax errors take precedence over range errors.
+pub trait TryParse: Sized {
+    /// Parses a decimal integer, or a hexadecimal, octal, or binary integer if the digits are
+    /// preceded by a `0x`, `0o`, or `0b` prefix respectively.
+    fn try_parse<S: AsRef<[u8]> + ?Sized>(src: &S) -> Result<Self, ParseIntError>;
+
+    /// Parses an integer in the specified radix. Radix prefixes are not accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    fn try_parse_radix<S: AsRef<[u8]> + ?Sized>(src: &S, radix: u32)
+        -> Result<Self, ParseIntError>;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Components of a syntactically valid integer string.
+struct Parts<'a> {
+    negative: bool,
+    radix: u32,
+    digits: &'a [u8],
+}
+
+/// Splits an integer string into its sign, radix and digits, and checks that all digits are valid.
+fn split(src: &[u8], radix: Option<u32>) -> Result<Parts<'_>, ParseIntError> {
+    if let Some(radix) = radix {
+        assert!((2..=36).contains(&radix), "radix must be in the range 2..=36, got {}", radix);
+    }
+
+    let (negative, src) = match src.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, src),
+    };
+
+    let (radix, digits) = match (radix, src) {
+        (Some(radix), _) => (radix, src),
+        (None, [b'0', b'x' | b'X', rest @ ..]) => (16, rest),
+        (None, [b'0', b'o' | b'O', rest @ ..]) => (8, rest),
+        (None, [b'0', b'b' | b'B', rest @ ..]) => (2, rest),
+        (None, _) => (10, src),
+    };
+
+    match (digits.first(), digits.last()) {
+        (None, _) | (_, None) => return Err(ParseIntError::Empty),
+        (Some(b'_'), _) | (_, Some(b'_')) => return Err(ParseIntError::InvalidDigit),
+        _ => (),
+    }
+
+    for &byte in digits {
+        if byte != b'_' && (byte as char).to_digit(radix).is_none() {
+            return Err(ParseIntError::InvalidDigit);
+        }
+    }
+
+    Ok(Parts { negative, radix, digits })
+}
+
+/// Iterates over the values of the digits of a string returned by [split].
+fn digit_values<'a>(parts: &Parts<'a>) -> impl Iterator<Item = u32> + 'a {
+    let radix = parts.radix;
+    parts.digits.iter()
+        .filter(|&&byte| byte != b'_')
+        .map(move |&byte| (byte as char).to_digit(radix).unwrap_or(0))
+}
+
+/// Accumulates the digits of a valid integer string into a value of the given type.
+///
+/// Negative numbers are accumulated downward so that the minimum value of a signed type can be
+/// parsed without overflowing.
+macro_rules! parse_parts {
+    ($ty:ident, $parts:expr) => {{
+        let parts = $parts;
+        let radix = parts.radix as $ty;
+        let mut value: $ty = 0;
+
+        for digit in digit_values(&parts) {
+            let digit = digit as $ty;
+            let next = match value.checked_mul(radix) {
+                None => None,
+                Some(n) if parts.negative => n.checked_sub(digit),
+                Some(n) => n.checked_add(digit),
+            };
+
+            value = match next {
+                None if parts.negative => return Err(RangeError::Underflow.into()),
+                None => return Err(RangeError::Overflow.into()),
+                Some(n) => n,
+            };
+        }
+
+        Ok(value)
+    }};
+}
+
+/// Implements parsing for integer types.
+macro_rules! impl_parse {
+    ($($ty:ident),*) => { $(
+        impl TryParse for $ty {
+            fn try_parse<S: AsRef<[u8]> + ?Sized>(src: &S) -> Result<$ty, ParseIntError> {
+                parse_parts!($ty, split(src.as_ref(), None)?)
+            }
+
+            fn try_parse_radix<S: AsRef<[u8]> + ?Sized>(src: &S, radix: u32)
+                -> Result<$ty, ParseIntError>
+            {
+                parse_parts!($ty, split(src.as_ref(), Some(radix))?)
+            }
+        }
+    )* };
+}
+
+impl_parse!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_try_parse() {
+    assert_eq!(i8::try_parse("127"), Ok(127));
+    assert_eq!(i8::try_parse("+127"), Ok(127));
+    assert_eq!(i8::try_parse("128"), Err(ParseIntError::OutOfRange(RangeError::Overflow)));
+    assert_eq!(i8::try_parse("-128"), Ok(-128));
+    assert_eq!(i8::try_parse("-129"), Err(ParseIntError::OutOfRange(RangeError::Underflow)));
+    assert_eq!(i8::try_parse("-0x80"), Ok(-128));
+    assert_eq!(i8::try_parse("0b0111_1111"), Ok(127));
+    assert_eq!(u16::try_parse("65_535"), Ok(65535));
+    assert_eq!(u16::try_parse("65_536"), Err(ParseIntError::OutOfRange(RangeError::Overflow)));
+    assert_eq!(u16::try_parse("0o177777"), Ok(65535));
+    assert_eq!(u16::try_parse("0xFFFF"), Ok(65535));
+    assert_eq!(u16::try_parse("-0"), Ok(0));
+    assert_eq!(u16::try_parse("-1"), Err(ParseIntError::OutOfRange(RangeError::Underflow)));
+    assert_eq!(u16::try_parse(b"1234".as_slice()), Ok(1234));
+    assert_eq!(i128::try_parse("-170141183460469231731687303715884105728"), Ok(i128::MIN));
+}
+
+#[test]
+fn test_try_parse_errors() {
+    assert_eq!(u8::try_parse(""), Err(ParseIntError::Empty));
+    assert_eq!(u8::try_parse("-"), Err(ParseIntError::Empty));
+    assert_eq!(u8::try_parse("0x"), Err(ParseIntError::Empty));
+    assert_eq!(u8::try_parse("_1"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse("1_"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse("0x_1"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse("1a"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse(" 1"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse("--1"), Err(ParseIntError::InvalidDigit));
+    assert_eq!(u8::try_parse("9999x"), Err(ParseIntError::InvalidDigit));
+}
+
+#[test]
+fn test_try_parse_radix() {
+    assert_eq!(u8::try_parse_radix("ff", 16), Ok(255));
+    assert_eq!(u8::try_parse_radix("100", 16),
+               Err(ParseIntError::OutOfRange(RangeError::Overflow)));
+    assert_eq!(u8::try_parse_radix("0xff", 16), Err(ParseIntError::InvalidDigit));
+    assert_eq!(i32::try_parse_radix("-zz", 36), Ok(-1295));
+    assert_eq!(i32::try_parse_radix("0b1", 10), Err(ParseIntError::InvalidDigit));
+    assert_eq!(i32::try_parse_radix("12", 2), Err(ParseIntError::InvalidDigit));
+}
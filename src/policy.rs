@@ -0,0 +1,255 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Overflow policies which select the behavior of arithmetic operators at compile time.
+//!
+//! Generic code takes a policy as a type parameter `P: OverflowPolicy` and calls operators such as
+//! `P::add(a, b)`. With the [Check] policy, operators return a `Result` as with the `Try*`
+//! traits. With the [Saturate], [Wrap] and [Panic] policies, operators return the result directly,
+//! using the `Saturating*`, `Wrapping*` and `Strict*` traits respectively. Division and remainder
+//! are only available with [Check] and [Panic].
+
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::strict_ops::{StrictAdd, StrictDiv, StrictMul, StrictNeg, StrictRem, StrictSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+/// Policy which returns a `Result` from each operator.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Check;
+
+/// Policy which returns the closest possible value in the event of an overflow or underflow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Saturate;
+
+/// Policy which wraps around in the event of an overflow or underflow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Wrap;
+
+/// Policy which panics when an operator fails.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Panic;
+
+/// Addition operator with the behavior selected by the policy `P`.
+pub trait PolicyAdd<P, Rhs = Self> {
+    type Output;
+    fn policy_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division operator with the behavior selected by the policy `P`.
+pub trait PolicyDiv<P, Rhs = Self> {
+    type Output;
+    fn policy_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication operator with the behavior selected by the policy `P`.
+pub trait PolicyMul<P, Rhs = Self> {
+    type Output;
+    fn policy_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Negation operator with the behavior selected by the policy `P`.
+pub trait PolicyNeg<P> {
+    type Output;
+    fn policy_neg(self) -> Self::Output;
+}
+
+/// Remainder operator with the behavior selected by the policy `P`.
+pub trait PolicyRem<P, Rhs = Self> {
+    type Output;
+    fn policy_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction operator with the behavior selected by the policy `P`.
+pub trait PolicySub<P, Rhs = Self> {
+    type Output;
+    fn policy_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Policy which selects the behavior of arithmetic operators in the event of an overflow,
+/// underflow or undefined result.
+pub trait OverflowPolicy: Sized {
+    #[track_caller]
+    fn add<L: PolicyAdd<Self, R>, R>(lhs: L, rhs: R) -> L::Output {
+        lhs.policy_add(rhs)
+    }
+
+    #[track_caller]
+    fn div<L: PolicyDiv<Self, R>, R>(lhs: L, rhs: R) -> L::Output {
+        lhs.policy_div(rhs)
+    }
+
+    #[track_caller]
+    fn mul<L: PolicyMul<Self, R>, R>(lhs: L, rhs: R) -> L::Output {
+        lhs.policy_mul(rhs)
+    }
+
+    #[track_caller]
+    fn neg<T: PolicyNeg<Self>>(value: T) -> T::Output {
+        value.policy_neg()
+    }
+
+    #[track_caller]
+    fn rem<L: PolicyRem<Self, R>, R>(lhs: L, rhs: R) -> L::Output {
+        lhs.policy_rem(rhs)
+    }
+
+    #[track_caller]
+    fn sub<L: PolicySub<Self, R>, R>(lhs: L, rhs: R) -> L::Output {
+        lhs.policy_sub(rhs)
+    }
+}
+
+impl OverflowPolicy for Check {}
+impl OverflowPolicy for Saturate {}
+impl OverflowPolicy for Wrap {}
+impl OverflowPolicy for Panic {}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements policy operators which delegate to checked operators.
+macro_rules! impl_try_ops {
+    { $(impl $trait:ident::$fn:ident => $try_trait:ident::$try_fn:ident;)* } => { $(
+        impl<T: $try_trait<R>, R> $trait<Check, R> for T {
+            type Output = Result<T::Output, T::Error>;
+
+            fn $fn(self, rhs: R) -> Result<T::Output, T::Error> {
+                $try_trait::$try_fn(self, rhs)
+            }
+        }
+    )* };
+}
+
+impl_try_ops! {
+    impl PolicyAdd::policy_add => TryAdd::try_add;
+    impl PolicyDiv::policy_div => TryDiv::try_div;
+    impl PolicyMul::policy_mul => TryMul::try_mul;
+    impl PolicyRem::policy_rem => TryRem::try_rem;
+    impl PolicySub::policy_sub => TrySub::try_sub;
+}
+
+/// Implements policy operators which delegate to infallible operators.
+macro_rules! impl_infallible_ops {
+    { $(impl $trait:ident<$policy:ident>::$fn:ident => $op_trait:ident::$op_fn:ident;)* } => { $(
+        impl<T: $op_trait<R>, R> $trait<$policy, R> for T {
+            type Output = T::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: R) -> T::Output {
+                $op_trait::$op_fn(self, rhs)
+            }
+        }
+    )* };
+}
+
+impl_infallible_ops! {
+    impl PolicyAdd<Saturate>::policy_add => SaturatingAdd::saturating_add;
+    impl PolicyMul<Saturate>::policy_mul => SaturatingMul::saturating_mul;
+    impl PolicySub<Saturate>::policy_sub => SaturatingSub::saturating_sub;
+    impl PolicyAdd<Wrap>::policy_add => WrappingAdd::wrapping_add;
+    impl PolicyMul<Wrap>::policy_mul => WrappingMul::wrapping_mul;
+    impl PolicySub<Wrap>::policy_sub => WrappingSub::wrapping_sub;
+    impl PolicyAdd<Panic>::policy_add => StrictAdd::strict_add;
+    impl PolicyDiv<Panic>::policy_div => StrictDiv::strict_div;
+    impl PolicyMul<Panic>::policy_mul => StrictMul::strict_mul;
+    impl PolicyRem<Panic>::policy_rem => StrictRem::strict_rem;
+    impl PolicySub<Panic>::policy_sub => StrictSub::strict_sub;
+}
+
+impl<T: TryNeg> PolicyNeg<Check> for T {
+    type Output = Result<T::Output, T::Error>;
+
+    fn policy_neg(self) -> Result<T::Output, T::Error> {
+        self.try_neg()
+    }
+}
+
+impl<T: StrictNeg> PolicyNeg<Panic> for T {
+    type Output = T::Output;
+
+    #[track_caller]
+    fn policy_neg(self) -> T::Output {
+        self.strict_neg()
+    }
+}
+
+impl<T: SaturatingNeg> PolicyNeg<Saturate> for T {
+    type Output = T::Output;
+
+    fn policy_neg(self) -> T::Output {
+        self.saturating_neg()
+    }
+}
+
+impl<T: WrappingNeg> PolicyNeg<Wrap> for T {
+    type Output = T::Output;
+
+    fn policy_neg(self) -> T::Output {
+        self.wrapping_neg()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Overflow, RangeError, Undefined, Underflow};
+    use crate::policy::{
+        Check, OverflowPolicy, Panic, PolicyAdd, PolicyMul, PolicySub, Saturate, Wrap,
+    };
+
+    fn sum_of_products<P>(values: &[u8]) -> <u8 as PolicyAdd<P>>::Output
+    where
+        P: OverflowPolicy,
+        u8: PolicyAdd<P, Output = u8> + PolicyMul<P, Output = u8>,
+    {
+        let mut total = 0;
+        for pair in values.chunks(2) {
+            total = P::add(total, P::mul(pair[0], pair[1]));
+        }
+        total
+    }
+
+    #[test]
+    fn test_policy_ops() {
+        assert_eq!(Check::add(200u8, 100u8), Err(Overflow));
+        assert_eq!(Check::sub(100u8, &200u8), Err(Underflow));
+        assert_eq!(Check::add(-100i8, -100i8), Err(RangeError::Underflow));
+        assert_eq!(Check::div(1u8, 0u8), Err(Undefined));
+        assert_eq!(Check::neg(-128i8), Err(Overflow));
+        assert_eq!(Saturate::add(200u8, 100u8), 255);
+        assert_eq!(Saturate::neg(-128i8), 127);
+        assert_eq!(Wrap::mul(16u8, 17u8), 16);
+        assert_eq!(Wrap::neg(&-128i8), -128);
+        assert_eq!(Panic::rem(7u8, 4u8), 3);
+        assert_eq!(Panic::sub(7u8, 4u8), 3);
+        assert_eq!(sum_of_products::<Saturate>(&[10, 20, 5, 12]), 255);
+        assert_eq!(sum_of_products::<Wrap>(&[10, 20, 5, 12]), 4);
+        assert_eq!(sum_of_products::<Panic>(&[10, 20, 5, 10]), 250);
+    }
+
+    #[test]
+    fn test_checked_policy_generic() {
+        fn difference<P>(a: u8, b: u8) -> <u8 as PolicySub<P>>::Output
+        where
+            P: OverflowPolicy,
+            u8: PolicySub<P>,
+        {
+            P::sub(a, b)
+        }
+
+        assert_eq!(difference::<Check>(1, 2), Err(Underflow));
+        assert_eq!(difference::<Saturate>(1, 2), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "arithmetic overflow")]
+    fn test_panic_policy() {
+        Panic::mul(16u8, 16u8);
+    }
+}
@@ -0,0 +1,561 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+//! Element-wise operators for slices.
+//!
+//! Each operator is provided in four forms:
+//!
+//! * `op_assign(dst, rhs)` updates each element of `dst` with the corresponding element of `rhs`.
+//! * `op_scalar_assign(dst, rhs)` updates each element of `dst` with the scalar `rhs`.
+//! * `op(out, lhs, rhs)` stores the results for each pair of elements of `lhs` and `rhs` in `out`.
+//! * `op_scalar(out, lhs, rhs)` stores the results for each element of `lhs` and the scalar `rhs`
+//!   in `out`.
+//!
+//! The kernels are written so that the compiler can vectorize them for primitive integer types;
+//! there are no architecture-specific implementations. Checked kernels compute the results for a
+//! block of elements before writing any of them, and apply the operator at most once to each
+//! element. When an operation fails, the elements before the failing index have been updated and
+//! the elements from the failing index onward have not.
+//!
+//! Reductions such as [try_dot] are computed with an accumulator which is wide enough that
+//! intermediate results never overflow, so a checked reduction only fails if the final result does
+//! not fit in the element type. These are provided for all primitive integer types except `i128`
+//! and `u128`.
+//!
+//! # Panics
+//!
+//! All functions panic if the slice arguments have different lengths.
+
+use core::array;
+
+use crate::error::{IndexedError, Overflow, RangeError};
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use crate::try_ops::{TryAdd, TryMul, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingSub};
+
+/// Number of elements which are computed at a time by the checked kernels.
+const BLOCK_LEN: usize = 64;
+
+/// Panics if two slice lengths are not equal.
+#[track_caller]
+fn check_len(lhs: usize, rhs: usize) {
+    assert_eq!(lhs, rhs, "slice lengths must be equal");
+}
+
+/// Applies an operator to each element of `dst` and the corresponding element of `rhs`.
+#[track_caller]
+fn apply_assign<T: Copy>(dst: &mut [T], rhs: &[T], f: impl Fn(T, T) -> T) {
+    check_len(dst.len(), rhs.len());
+
+    for (lhs, &rhs) in dst.iter_mut().zip(rhs) {
+        *lhs = f(*lhs, rhs);
+    }
+}
+
+/// Applies an operator to each element of `dst` and a scalar.
+fn apply_scalar_assign<T: Copy>(dst: &mut [T], rhs: T, f: impl Fn(T, T) -> T) {
+    for lhs in dst.iter_mut() {
+        *lhs = f(*lhs, rhs);
+    }
+}
+
+/// Applies a checked operator to each element of `dst`, stopping at the first failure. The operator
+/// is given the index and value of each element.
+fn try_apply<T: Copy, E>(
+    dst: &mut [T],
+    f: impl Fn(usize, T) -> Result<T, E>,
+) -> Result<(), IndexedError<E>> {
+    let len = dst.len();
+    let mut blocks = dst.chunks_exact_mut(BLOCK_LEN);
+
+    for (block, dst) in (&mut blocks).enumerate() {
+        // Computing the results of a whole block before writing any of them allows the block to be
+        // vectorized.
+        let offset = block * BLOCK_LEN;
+        let results: [Result<T, E>; BLOCK_LEN] =
+            array::from_fn(|index| f(offset + index, dst[index]));
+
+        for (index, (lhs, result)) in dst.iter_mut().zip(results).enumerate() {
+            match result {
+                Ok(n) => *lhs = n,
+                Err(err) => return Err(IndexedError::new(offset + index, err)),
+            }
+        }
+    }
+
+    let remainder = blocks.into_remainder();
+    let offset = len - remainder.len();
+
+    for (index, lhs) in remainder.iter_mut().enumerate() {
+        match f(offset + index, *lhs) {
+            Ok(n) => *lhs = n,
+            Err(err) => return Err(IndexedError::new(offset + index, err)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies a checked operator to each element of `dst` and the corresponding element of `rhs`.
+#[track_caller]
+fn try_apply_assign<T: Copy, E>(
+    dst: &mut [T],
+    rhs: &[T],
+    f: impl Fn(T, T) -> Result<T, E>,
+) -> Result<(), IndexedError<E>> {
+    check_len(dst.len(), rhs.len());
+    try_apply(dst, |index, lhs| f(lhs, rhs[index]))
+}
+
+/// Applies a checked operator to each element of `dst` and a scalar.
+fn try_apply_scalar_assign<T: Copy, E>(
+    dst: &mut [T],
+    rhs: T,
+    f: impl Fn(T, T) -> Result<T, E>,
+) -> Result<(), IndexedError<E>> {
+    try_apply(dst, |_, lhs| f(lhs, rhs))
+}
+
+/// Defines saturating or wrapping slice kernels.
+macro_rules! define_kernels {
+    { $(
+        $trait:ident::$fn:ident: $assign:ident, $scalar:ident, $scalar_assign:ident;
+    )* } => { $(
+        #[doc = concat!("Applies [", stringify!($trait), "] to each pair of elements of `lhs` and \
+                         `rhs`, storing the results in `out`.")]
+        #[track_caller]
+        pub fn $fn<T: Copy + $trait<Output = T>>(out: &mut [T], lhs: &[T], rhs: &[T]) {
+            out.copy_from_slice(lhs);
+            $assign(out, rhs);
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `dst` and the \
+                         corresponding element of `rhs` in place.")]
+        #[track_caller]
+        pub fn $assign<T: Copy + $trait<Output = T>>(dst: &mut [T], rhs: &[T]) {
+            apply_assign(dst, rhs, $trait::$fn);
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `lhs` and a \
+                         scalar, storing the results in `out`.")]
+        #[track_caller]
+        pub fn $scalar<T: Copy + $trait<Output = T>>(out: &mut [T], lhs: &[T], rhs: T) {
+            out.copy_from_slice(lhs);
+            $scalar_assign(out, rhs);
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `dst` and a \
+                         scalar in place.")]
+        pub fn $scalar_assign<T: Copy + $trait<Output = T>>(dst: &mut [T], rhs: T) {
+            apply_scalar_assign(dst, rhs, $trait::$fn);
+        }
+    )* };
+}
+
+/// Defines checked slice kernels.
+macro_rules! define_try_kernels {
+    { $(
+        $trait:ident::$fn:ident: $assign:ident, $scalar:ident, $scalar_assign:ident;
+    )* } => { $(
+        #[doc = concat!("Applies [", stringify!($trait), "] to each pair of elements of `lhs` and \
+                         `rhs`, storing the results in `out`.\n\nOn failure, the elements of \
+                         `out` from the failing index onward are copied from `lhs`.")]
+        #[track_caller]
+        pub fn $fn<T: Copy + $trait<Output = T>>(out: &mut [T], lhs: &[T], rhs: &[T])
+            -> Result<(), IndexedError<T::Error>>
+        {
+            out.copy_from_slice(lhs);
+            $assign(out, rhs)
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `dst` and the \
+                         corresponding element of `rhs` in place.")]
+        #[track_caller]
+        pub fn $assign<T: Copy + $trait<Output = T>>(dst: &mut [T], rhs: &[T])
+            -> Result<(), IndexedError<T::Error>>
+        {
+            try_apply_assign(dst, rhs, $trait::$fn)
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `lhs` and a \
+                         scalar, storing the results in `out`.\n\nOn failure, the elements of \
+                         `out` from the failing index onward are copied from `lhs`.")]
+        #[track_caller]
+        pub fn $scalar<T: Copy + $trait<Output = T>>(out: &mut [T], lhs: &[T], rhs: T)
+            -> Result<(), IndexedError<T::Error>>
+        {
+            out.copy_from_slice(lhs);
+            $scalar_assign(out, rhs)
+        }
+
+        #[doc = concat!("Applies [", stringify!($trait), "] to each element of `dst` and a \
+                         scalar in place.")]
+        pub fn $scalar_assign<T: Copy + $trait<Output = T>>(dst: &mut [T], rhs: T)
+            -> Result<(), IndexedError<T::Error>>
+        {
+            try_apply_scalar_assign(dst, rhs, $trait::$fn)
+        }
+    )* };
+}
+
+define_kernels! {
+    SaturatingAdd::saturating_add:
+        saturating_add_assign, saturating_add_scalar, saturating_add_scalar_assign;
+    SaturatingMul::saturating_mul:
+        saturating_mul_assign, saturating_mul_scalar, saturating_mul_scalar_assign;
+    SaturatingSub::saturating_sub:
+        saturating_sub_assign, saturating_sub_scalar, saturating_sub_scalar_assign;
+    WrappingAdd::wrapping_add:
+        wrapping_add_assign, wrapping_add_scalar, wrapping_add_scalar_assign;
+    WrappingMul::wrapping_mul:
+        wrapping_mul_assign, wrapping_mul_scalar, wrapping_mul_scalar_assign;
+    WrappingSub::wrapping_sub:
+        wrapping_sub_assign, wrapping_sub_scalar, wrapping_sub_scalar_assign;
+}
+
+define_try_kernels! {
+    TryAdd::try_add: try_add_assign, try_add_scalar, try_add_scalar_assign;
+    TryMul::try_mul: try_mul_assign, try_mul_scalar, try_mul_scalar_assign;
+    TrySub::try_sub: try_sub_assign, try_sub_scalar, try_sub_scalar_assign;
+}
+
+/// Element types which support reductions with a wide accumulator.
+pub trait WideReduce: Sized {
+    type Error;
+
+    /// Returns the dot product of two slices, or an error if it does not fit in `Self`.
+    fn try_dot(lhs: &[Self], rhs: &[Self]) -> Result<Self, Self::Error>;
+    /// Returns the dot product of two slices, saturating at the bounds of `Self`.
+    fn saturating_dot(lhs: &[Self], rhs: &[Self]) -> Self;
+    /// Returns the dot product of two slices, wrapping around at the bounds of `Self`.
+    fn wrapping_dot(lhs: &[Self], rhs: &[Self]) -> Self;
+    /// Returns the sum of the squares of the elements of a slice, or an error if it does not fit in
+    /// `Self`.
+    fn try_sum_squares(values: &[Self]) -> Result<Self, Self::Error>;
+    /// Returns the sum of the absolute values of the elements of a slice, or an error if it does
+    /// not fit in `Self`.
+    fn try_l1_norm(values: &[Self]) -> Result<Self, Self::Error>;
+}
+
+/// Returns the dot product of two slices, or an error if it does not fit in `T`.
+#[track_caller]
+pub fn try_dot<T: WideReduce>(lhs: &[T], rhs: &[T]) -> Result<T, T::Error> {
+    T::try_dot(lhs, rhs)
+}
+
+/// Returns the dot product of two slices, saturating at the bounds of `T`.
+#[track_caller]
+pub fn saturating_dot<T: WideReduce>(lhs: &[T], rhs: &[T]) -> T {
+    T::saturating_dot(lhs, rhs)
+}
+
+/// Returns the dot product of two slices, wrapping around at the bounds of `T`.
+#[track_caller]
+pub fn wrapping_dot<T: WideReduce>(lhs: &[T], rhs: &[T]) -> T {
+    T::wrapping_dot(lhs, rhs)
+}
+
+/// Returns the sum of the squares of the elements of a slice, or an error if it does not fit in
+/// `T`.
+pub fn try_sum_squares<T: WideReduce>(values: &[T]) -> Result<T, T::Error> {
+    T::try_sum_squares(values)
+}
+
+/// Returns the sum of the absolute values of the elements of a slice, or an error if it does not
+/// fit in `T`.
+pub fn try_l1_norm<T: WideReduce>(values: &[T]) -> Result<T, T::Error> {
+    T::try_l1_norm(values)
+}
+
+/// Sum of 128-bit terms with a count of the number of times that the sum has wrapped around.
+///
+/// The exact sum is `value + carries * 2^128`. Since the element types are at most 64 bits wide, a
+/// sum with a nonzero carry count never fits in the element type.
+struct WideSum<W> {
+    value: W,
+    carries: i64,
+}
+
+impl WideSum<i128> {
+    /// Sums signed terms.
+    fn new(terms: impl Iterator<Item = i128>) -> WideSum<i128> {
+        terms.fold(WideSum { value: 0, carries: 0 }, |sum, term| {
+            let (value, wrapped) = sum.value.overflowing_add(term);
+            let carry = match wrapped {
+                false => 0,
+                true if term > 0 => 1,
+                true => -1,
+            };
+            WideSum { value, carries: sum.carries + carry }
+        })
+    }
+
+    /// Converts the sum to a narrower signed type.
+    fn try_into_narrow<T: TryFrom<i128>>(self) -> Result<T, RangeError> {
+        match T::try_from(self.value) {
+            Ok(n) if self.carries == 0 => Ok(n),
+            _ if self.carries > 0 || (self.carries == 0 && self.value > 0) => {
+                Err(RangeError::Overflow)
+            },
+            _ => Err(RangeError::Underflow),
+        }
+    }
+}
+
+impl WideSum<u128> {
+    /// Sums unsigned terms.
+    fn new(terms: impl Iterator<Item = u128>) -> WideSum<u128> {
+        terms.fold(WideSum { value: 0, carries: 0 }, |sum, term| {
+            let (value, wrapped) = sum.value.overflowing_add(term);
+            WideSum { value, carries: sum.carries + wrapped as i64 }
+        })
+    }
+
+    /// Converts the sum to a narrower unsigned type.
+    fn try_into_narrow<T: TryFrom<u128>>(self) -> Result<T, Overflow> {
+        match T::try_from(self.value) {
+            Ok(n) if self.carries == 0 => Ok(n),
+            _ => Err(Overflow),
+        }
+    }
+}
+
+/// Implements wide reductions for signed integer types.
+macro_rules! impl_int_reduce {
+    ($($ty:ident),*) => { $(
+        impl WideReduce for $ty {
+            type Error = RangeError;
+
+            #[track_caller]
+            fn try_dot(lhs: &[$ty], rhs: &[$ty]) -> Result<$ty, RangeError> {
+                check_len(lhs.len(), rhs.len());
+                let terms = lhs.iter().zip(rhs).map(|(&lhs, &rhs)| lhs as i128 * rhs as i128);
+                WideSum::<i128>::new(terms).try_into_narrow()
+            }
+
+            #[track_caller]
+            fn saturating_dot(lhs: &[$ty], rhs: &[$ty]) -> $ty {
+                match Self::try_dot(lhs, rhs) {
+                    Ok(n) => n,
+                    Err(RangeError::Overflow) => $ty::MAX,
+                    Err(RangeError::Underflow) => $ty::MIN,
+                }
+            }
+
+            #[track_caller]
+            fn wrapping_dot(lhs: &[$ty], rhs: &[$ty]) -> $ty {
+                check_len(lhs.len(), rhs.len());
+                lhs.iter().zip(rhs).fold(0, |sum: $ty, (&lhs, &rhs)| {
+                    sum.wrapping_add(lhs.wrapping_mul(rhs))
+                })
+            }
+
+            fn try_sum_squares(values: &[$ty]) -> Result<$ty, RangeError> {
+                let terms = values.iter().map(|&n| n as i128 * n as i128);
+                WideSum::<i128>::new(terms).try_into_narrow()
+            }
+
+            fn try_l1_norm(values: &[$ty]) -> Result<$ty, RangeError> {
+                let terms = values.iter().map(|&n| (n as i128).abs());
+                WideSum::<i128>::new(terms).try_into_narrow()
+            }
+        }
+    )* };
+}
+
+impl_int_reduce!(i8, i16, i32, i64, isize);
+
+/// Implements wide reductions for unsigned integer types.
+macro_rules! impl_uint_reduce {
+    ($($ty:ident),*) => { $(
+        impl WideReduce for $ty {
+            type Error = Overflow;
+
+            #[track_caller]
+            fn try_dot(lhs: &[$ty], rhs: &[$ty]) -> Result<$ty, Overflow> {
+                check_len(lhs.len(), rhs.len());
+                let terms = lhs.iter().zip(rhs).map(|(&lhs, &rhs)| lhs as u128 * rhs as u128);
+                WideSum::<u128>::new(terms).try_into_narrow()
+            }
+
+            #[track_caller]
+            fn saturating_dot(lhs: &[$ty], rhs: &[$ty]) -> $ty {
+                Self::try_dot(lhs, rhs).unwrap_or($ty::MAX)
+            }
+
+            #[track_caller]
+            fn wrapping_dot(lhs: &[$ty], rhs: &[$ty]) -> $ty {
+                check_len(lhs.len(), rhs.len());
+                lhs.iter().zip(rhs).fold(0, |sum: $ty, (&lhs, &rhs)| {
+                    sum.wrapping_add(lhs.wrapping_mul(rhs))
+                })
+            }
+
+            fn try_sum_squares(values: &[$ty]) -> Result<$ty, Overflow> {
+                let terms = values.iter().map(|&n| n as u128 * n as u128);
+                WideSum::<u128>::new(terms).try_into_narrow()
+            }
+
+            fn try_l1_norm(values: &[$ty]) -> Result<$ty, Overflow> {
+                WideSum::<u128>::new(values.iter().map(|&n| n as u128)).try_into_narrow()
+            }
+        }
+    )* };
+}
+
+impl_uint_reduce!(u8, u16, u32, u64, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_saturating_kernels() {
+    let mut dst = [100u8, 200, 250];
+    saturating_add_assign(&mut dst, &[100, 100, 100]);
+    assert_eq!(dst, [200, 255, 255]);
+    saturating_sub_scalar_assign(&mut dst, 201);
+    assert_eq!(dst, [0, 54, 54]);
+
+    let mut out = [0i16; 3];
+    saturating_mul(&mut out, &[100, -200, 300], &[100, 200, -300]);
+    assert_eq!(out, [10000, i16::MIN, i16::MIN]);
+    saturating_add_scalar(&mut out, &[100, -200, i16::MAX], 1);
+    assert_eq!(out, [101, -199, i16::MAX]);
+}
+
+#[test]
+fn test_wrapping_kernels() {
+    let mut dst = [100u8, 200, 250];
+    wrapping_add_assign(&mut dst, &[100, 100, 100]);
+    assert_eq!(dst, [200, 44, 94]);
+    wrapping_mul_scalar_assign(&mut dst, 2);
+    assert_eq!(dst, [144, 88, 188]);
+
+    let mut out = [0u8; 3];
+    wrapping_sub(&mut out, &[0, 1, 2], &[1, 1, 1]);
+    assert_eq!(out, [255, 0, 1]);
+    wrapping_mul_scalar(&mut out, &[1, 2, 128], 2);
+    assert_eq!(out, [2, 4, 0]);
+}
+
+#[test]
+fn test_try_kernels() {
+    use crate::error::{Overflow, RangeError};
+
+    let mut dst = [0u8; 200];
+    let rhs: [u8; 200] = core::array::from_fn(|i| if i == 150 { 255 } else { 1 });
+    try_add_assign(&mut dst, &rhs).unwrap();
+    assert_eq!(try_add_assign(&mut dst, &rhs), Err(IndexedError::new(150, Overflow)));
+    let expected: [u8; 200] = core::array::from_fn(|i| if i < 150 { 2 } else { rhs[i] });
+    assert_eq!(dst, expected);
+
+    let mut dst = [1i8, 2, 3, -100];
+    assert_eq!(try_mul_scalar_assign(&mut dst, 2),
+               Err(IndexedError::new(3, RangeError::Underflow)));
+    assert_eq!(dst, [2, 4, 6, -100]);
+
+    let mut out = [0u8; 3];
+    assert_eq!(try_sub(&mut out, &[3, 2, 1], &[1, 1, 1]), Ok(()));
+    assert_eq!(out, [2, 1, 0]);
+    assert_eq!(try_sub_scalar(&mut out, &[3, 2, 1], 2).map_err(|err| err.index), Err(2));
+    assert_eq!(out, [1, 0, 1]);
+}
+
+#[test]
+fn test_try_kernel_calls() {
+    use core::cell::Cell;
+
+    // The operator is applied once to each element up to the end of the block containing the first
+    // failure, and the results after the failure are discarded.
+    let calls = Cell::new(0);
+    let mut dst = [0u8; 200];
+    let result = try_apply_scalar_assign(&mut dst, 1, |lhs, rhs| {
+        calls.set(calls.get() + 1);
+        match calls.get() {
+            100 => Err(()),
+            _ => Ok(lhs + rhs),
+        }
+    });
+    assert_eq!(result, Err(IndexedError::new(99, ())));
+    assert_eq!(calls.get(), 128);
+    assert!(dst[..99].iter().all(|&n| n == 1));
+    assert!(dst[99..].iter().all(|&n| n == 0));
+
+    calls.set(0);
+    let mut dst = [0u8; 70];
+    let result = try_apply_assign(&mut dst, &[1; 70], |lhs, rhs| {
+        calls.set(calls.get() + 1);
+        match calls.get() {
+            68 => Err(()),
+            _ => Ok(lhs + rhs),
+        }
+    });
+    assert_eq!(result, Err(IndexedError::new(67, ())));
+    assert_eq!(calls.get(), 68);
+    assert!(dst[..67].iter().all(|&n| n == 1));
+    assert!(dst[67..].iter().all(|&n| n == 0));
+}
+
+#[test]
+#[should_panic]
+fn test_kernel_length_mismatch() {
+    saturating_add_assign(&mut [0u8; 2], &[0u8; 3]);
+}
+
+#[test]
+fn test_try_dot() {
+    use crate::error::Overflow;
+
+    assert_eq!(try_dot::<i8>(&[1, 2, 3], &[4, 5, 6]), Ok(32));
+    // The intermediate sum overflows, but the final result fits.
+    assert_eq!(try_dot::<i8>(&[100, 100, -100], &[1, 1, 1]), Ok(100));
+    assert_eq!(try_dot::<i8>(&[100, 28], &[1, 1]), Err(RangeError::Overflow));
+    assert_eq!(try_dot::<i8>(&[-100, 29], &[1, -1]), Err(RangeError::Underflow));
+    assert_eq!(try_dot::<u8>(&[16, 16], &[16, 0]), Err(Overflow));
+    assert_eq!(try_dot::<u8>(&[], &[]), Ok(0));
+
+    // Products of 64-bit values which overflow the 128-bit accumulator and then come back.
+    let big = [i64::MAX, i64::MAX, i64::MAX, i64::MAX, -i64::MAX, -i64::MAX, -i64::MAX, -i64::MAX];
+    let signs = [i64::MAX; 8];
+    assert_eq!(try_dot(&big, &signs), Ok(0));
+    assert_eq!(try_dot(&big[..4], &signs[..4]), Err(RangeError::Overflow));
+    assert_eq!(try_dot(&big[4..], &signs[4..]), Err(RangeError::Underflow));
+
+    let big = [u64::MAX; 4];
+    assert_eq!(try_dot(&big, &big), Err(Overflow));
+    assert_eq!(try_dot(&big, &[1, 0, 0, 0]), Ok(u64::MAX));
+}
+
+#[test]
+fn test_saturating_wrapping_dot() {
+    assert_eq!(saturating_dot::<i8>(&[100, 100, -100], &[1, 1, 1]), 100);
+    assert_eq!(saturating_dot::<i8>(&[100, 28], &[1, 1]), 127);
+    assert_eq!(saturating_dot::<i8>(&[-100, 29], &[1, -1]), -128);
+    assert_eq!(saturating_dot::<u8>(&[16, 16], &[16, 0]), 255);
+    assert_eq!(wrapping_dot::<i8>(&[100, 28], &[1, 1]), -128);
+    assert_eq!(wrapping_dot::<u8>(&[16, 16], &[16, 1]), 16);
+}
+
+#[test]
+fn test_try_sum_squares() {
+    use crate::error::Overflow;
+
+    assert_eq!(try_sum_squares::<i8>(&[-3, 4, 10]), Ok(125));
+    assert_eq!(try_sum_squares::<i8>(&[-3, 4, 11]), Err(RangeError::Overflow));
+    assert_eq!(try_sum_squares::<u8>(&[15, 5]), Ok(250));
+    assert_eq!(try_sum_squares::<u8>(&[16]), Err(Overflow));
+}
+
+#[test]
+fn test_try_l1_norm() {
+    use crate::error::Overflow;
+
+    assert_eq!(try_l1_norm::<i8>(&[-100, 27]), Ok(127));
+    assert_eq!(try_l1_norm::<i8>(&[-128]), Err(RangeError::Overflow));
+    assert_eq!(try_l1_norm::<u8>(&[200, 55]), Ok(255));
+    assert_eq!(try_l1_norm::<u8>(&[200, 56]), Err(Overflow));
+}
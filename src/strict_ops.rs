@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::fmt::Display;
+
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+
+/// Addition operator which panics in the event of an overflow or underflow.
+pub trait StrictAdd<Rhs = Self> {
+    type Output;
+    fn strict_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division operator which panics if the result is undefined or out of range.
+pub trait StrictDiv<Rhs = Self> {
+    type Output;
+    fn strict_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication operator which panics in the event of an overflow or underflow.
+pub trait StrictMul<Rhs = Self> {
+    type Output;
+    fn strict_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Negation operator which panics in the event of an overflow or underflow.
+pub trait StrictNeg {
+    type Output;
+    fn strict_neg(self) -> Self::Output;
+}
+
+/// Remainder operator which panics if the result is undefined or out of range.
+pub trait StrictRem<Rhs = Self> {
+    type Output;
+    fn strict_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction operator which panics in the event of an overflow or underflow.
+pub trait StrictSub<Rhs = Self> {
+    type Output;
+    fn strict_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+// Strict operators are implemented for every type which implements the corresponding checked
+// operator, and panic with the error returned by the checked operator.
+
+/// Implements binary strict operators.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident => $try_trait:ident::$try_fn:ident;)* } => { $(
+        impl<T, R> $trait<R> for T
+        where
+            T: $try_trait<R>,
+            T::Error: Display,
+        {
+            type Output = T::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: R) -> T::Output {
+                match $try_trait::$try_fn(self, rhs) {
+                    Ok(value) => value,
+                    Err(err) => panic!("{}", err),
+                }
+            }
+        }
+    )* };
+}
+
+impl_binary_ops! {
+    impl StrictAdd::strict_add => TryAdd::try_add;
+    impl StrictDiv::strict_div => TryDiv::try_div;
+    impl StrictMul::strict_mul => TryMul::try_mul;
+    impl StrictRem::strict_rem => TryRem::try_rem;
+    impl StrictSub::strict_sub => TrySub::try_sub;
+}
+
+impl<T> StrictNeg for T
+where
+    T: TryNeg,
+    T::Error: Display,
+{
+    type Output = T::Output;
+
+    #[track_caller]
+    fn strict_neg(self) -> T::Output {
+        match self.try_neg() {
+            Ok(value) => value,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_strict_ops() {
+    assert_eq!(StrictAdd::strict_add(200u8, 55), 255);
+    assert_eq!(StrictDiv::strict_div(&7i32, &-2), -3);
+    assert_eq!(StrictMul::strict_mul(16u16, 16), 256);
+    assert_eq!(StrictNeg::strict_neg(-127i8), 127);
+    assert_eq!(StrictRem::strict_rem(7u8, 4), 3);
+    assert_eq!(StrictSub::strict_sub(7u8, 7), 0);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic overflow")]
+fn test_strict_add_overflow() {
+    StrictAdd::strict_add(200u8, 56);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic result undefined")]
+fn test_strict_div_undefined() {
+    StrictDiv::strict_div(1i32, 0);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic overflow")]
+fn test_strict_neg_overflow() {
+    StrictNeg::strict_neg(i8::MIN);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic result undefined")]
+fn test_strict_rem_undefined() {
+    StrictRem::strict_rem(&7u32, 0);
+}
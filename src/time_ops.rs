@@ -0,0 +1,406 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
+
+use crate::error::{ArithmeticError, Overflow, Undefined, Underflow};
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryRem, TrySub};
+
+/// Implements binary operators for reference types.
+macro_rules! impl_binary_ref_ops {
+    { $(impl $trait:ident<$rhs:ty>::$fn:ident for $ty:ty;)* } => { $(
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+            type Error = <$ty as $trait<$rhs>>::Error;
+
+            fn $fn(self, rhs: &'r $rhs) -> Result<Self::Output, Self::Error> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements binary saturating operators for reference types.
+macro_rules! impl_saturating_ref_ops {
+    { $(impl $trait:ident<$rhs:ty>::$fn:ident for $ty:ty;)* } => { $(
+        impl<'a> $trait<$rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+
+            fn $fn(self, rhs: $rhs) -> Self::Output {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $rhs> for $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+
+            fn $fn(self, rhs: &'r $rhs) -> Self::Output {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $rhs> for &'a $ty {
+            type Output = <$ty as $trait<$rhs>>::Output;
+
+            fn $fn(self, rhs: &'r $rhs) -> Self::Output {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+impl TryAdd for Duration {
+    type Output = Duration;
+    type Error = Overflow;
+
+    fn try_add(self, rhs: Duration) -> Result<Duration, Overflow> {
+        match self.checked_add(rhs) {
+            None => Err(Overflow),
+            Some(d) => Ok(d),
+        }
+    }
+}
+
+/// Divides a duration by an integer, rounding toward zero.
+impl TryDiv<u32> for Duration {
+    type Output = Duration;
+    type Error = Undefined;
+
+    fn try_div(self, rhs: u32) -> Result<Duration, Undefined> {
+        match self.checked_div(rhs) {
+            None => Err(Undefined),
+            Some(d) => Ok(d),
+        }
+    }
+}
+
+/// Returns the number of times that `rhs` fits in `self`, rounding toward zero.
+impl TryDiv for Duration {
+    type Output = u128;
+    type Error = Undefined;
+
+    fn try_div(self, rhs: Duration) -> Result<u128, Undefined> {
+        match self.as_nanos().checked_div(rhs.as_nanos()) {
+            None => Err(Undefined),
+            Some(n) => Ok(n),
+        }
+    }
+}
+
+impl TryMul<u32> for Duration {
+    type Output = Duration;
+    type Error = Overflow;
+
+    fn try_mul(self, rhs: u32) -> Result<Duration, Overflow> {
+        match self.checked_mul(rhs) {
+            None => Err(Overflow),
+            Some(d) => Ok(d),
+        }
+    }
+}
+
+/// Multiplies a duration by a floating point factor.
+///
+/// Fails with [ArithmeticError::Undefined] if the factor is negative or the product is not a
+/// number.
+impl TryMul<f64> for Duration {
+    type Output = Duration;
+    type Error = ArithmeticError;
+
+    fn try_mul(self, rhs: f64) -> Result<Duration, ArithmeticError> {
+        let secs = self.as_secs_f64() * rhs;
+
+        if secs.is_nan() || rhs < 0.0 {
+            Err(ArithmeticError::Undefined)
+        } else {
+            Duration::try_from_secs_f64(secs).map_err(|_| ArithmeticError::Overflow)
+        }
+    }
+}
+
+/// Returns the remainder after dividing `self` by `rhs` a whole number of times.
+impl TryRem for Duration {
+    type Output = Duration;
+    type Error = Undefined;
+
+    fn try_rem(self, rhs: Duration) -> Result<Duration, Undefined> {
+        match self.as_nanos().checked_rem(rhs.as_nanos()) {
+            None => Err(Undefined),
+            // The remainder is less than `rhs`, so it always fits.
+            Some(n) => Ok(Duration::new((n / 1_000_000_000) as u64, (n % 1_000_000_000) as u32)),
+        }
+    }
+}
+
+impl TrySub for Duration {
+    type Output = Duration;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: Duration) -> Result<Duration, Underflow> {
+        match self.checked_sub(rhs) {
+            None => Err(Underflow),
+            Some(d) => Ok(d),
+        }
+    }
+}
+
+impl SaturatingAdd for Duration {
+    type Output = Duration;
+
+    fn saturating_add(self, rhs: Duration) -> Duration {
+        self.saturating_add(rhs)
+    }
+}
+
+impl SaturatingMul<u32> for Duration {
+    type Output = Duration;
+
+    fn saturating_mul(self, rhs: u32) -> Duration {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl SaturatingSub for Duration {
+    type Output = Duration;
+
+    fn saturating_sub(self, rhs: Duration) -> Duration {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl_binary_ref_ops! {
+    impl TryAdd<Duration>::try_add for Duration;
+    impl TryDiv<u32>::try_div for Duration;
+    impl TryDiv<Duration>::try_div for Duration;
+    impl TryMul<u32>::try_mul for Duration;
+    impl TryMul<f64>::try_mul for Duration;
+    impl TryRem<Duration>::try_rem for Duration;
+    impl TrySub<Duration>::try_sub for Duration;
+}
+
+impl_saturating_ref_ops! {
+    impl SaturatingAdd<Duration>::saturating_add for Duration;
+    impl SaturatingMul<u32>::saturating_mul for Duration;
+    impl SaturatingSub<Duration>::saturating_sub for Duration;
+}
+
+#[cfg(feature = "std")]
+impl TryAdd<Duration> for Instant {
+    type Output = Instant;
+    type Error = Overflow;
+
+    fn try_add(self, rhs: Duration) -> Result<Instant, Overflow> {
+        match self.checked_add(rhs) {
+            None => Err(Overflow),
+            Some(t) => Ok(t),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TrySub<Duration> for Instant {
+    type Output = Instant;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: Duration) -> Result<Instant, Underflow> {
+        match self.checked_sub(rhs) {
+            None => Err(Underflow),
+            Some(t) => Ok(t),
+        }
+    }
+}
+
+/// Returns the amount of time elapsed from `rhs` to `self`, or [Underflow] if `rhs` is later than
+/// `self`.
+#[cfg(feature = "std")]
+impl TrySub for Instant {
+    type Output = Duration;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: Instant) -> Result<Duration, Underflow> {
+        match self.checked_duration_since(rhs) {
+            None => Err(Underflow),
+            Some(d) => Ok(d),
+        }
+    }
+}
+
+/// Returns the amount of time elapsed from `rhs` to `self`, or zero if `rhs` is later than `self`.
+#[cfg(feature = "std")]
+impl SaturatingSub for Instant {
+    type Output = Duration;
+
+    fn saturating_sub(self, rhs: Instant) -> Duration {
+        self.saturating_duration_since(rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryAdd<Duration> for SystemTime {
+    type Output = SystemTime;
+    type Error = Overflow;
+
+    fn try_add(self, rhs: Duration) -> Result<SystemTime, Overflow> {
+        match self.checked_add(rhs) {
+            None => Err(Overflow),
+            Some(t) => Ok(t),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TrySub<Duration> for SystemTime {
+    type Output = SystemTime;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: Duration) -> Result<SystemTime, Underflow> {
+        match self.checked_sub(rhs) {
+            None => Err(Underflow),
+            Some(t) => Ok(t),
+        }
+    }
+}
+
+/// Returns the amount of time elapsed from `rhs` to `self`, or [Underflow] if `rhs` is later than
+/// `self`, which may happen if the system clock was adjusted backwards.
+#[cfg(feature = "std")]
+impl TrySub for SystemTime {
+    type Output = Duration;
+    type Error = Underflow;
+
+    fn try_sub(self, rhs: SystemTime) -> Result<Duration, Underflow> {
+        self.duration_since(rhs).map_err(|_| Underflow)
+    }
+}
+
+/// Returns the amount of time elapsed from `rhs` to `self`, or zero if `rhs` is later than `self`.
+#[cfg(feature = "std")]
+impl SaturatingSub for SystemTime {
+    type Output = Duration;
+
+    fn saturating_sub(self, rhs: SystemTime) -> Duration {
+        self.duration_since(rhs).unwrap_or(Duration::ZERO)
+    }
+}
+
+#[cfg(feature = "std")]
+impl_binary_ref_ops! {
+    impl TryAdd<Duration>::try_add for Instant;
+    impl TrySub<Duration>::try_sub for Instant;
+    impl TrySub<Instant>::try_sub for Instant;
+    impl TryAdd<Duration>::try_add for SystemTime;
+    impl TrySub<Duration>::try_sub for SystemTime;
+    impl TrySub<SystemTime>::try_sub for SystemTime;
+}
+
+#[cfg(feature = "std")]
+impl_saturating_ref_ops! {
+    impl SaturatingSub<Instant>::saturating_sub for Instant;
+    impl SaturatingSub<SystemTime>::saturating_sub for SystemTime;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_duration_try_add() {
+    assert_eq!(Duration::from_secs(1).try_add(Duration::from_millis(500)),
+               Ok(Duration::from_millis(1500)));
+    assert_eq!(Duration::MAX.try_add(Duration::from_nanos(1)), Err(Overflow));
+}
+
+#[test]
+fn test_duration_try_div() {
+    assert_eq!(Duration::from_secs(3).try_div(2u32), Ok(Duration::from_millis(1500)));
+    assert_eq!(Duration::from_secs(3).try_div(0u32), Err(Undefined));
+    assert_eq!(Duration::from_secs(3).try_div(Duration::from_millis(700)), Ok(4));
+    assert_eq!(Duration::MAX.try_div(Duration::from_nanos(1)), Ok(Duration::MAX.as_nanos()));
+    assert_eq!(Duration::from_secs(3).try_div(Duration::ZERO), Err(Undefined));
+}
+
+#[test]
+fn test_duration_try_mul() {
+    assert_eq!(Duration::from_secs(2).try_mul(3u32), Ok(Duration::from_secs(6)));
+    assert_eq!(Duration::MAX.try_mul(2u32), Err(Overflow));
+    assert_eq!(Duration::from_secs(2).try_mul(1.5), Ok(Duration::from_secs(3)));
+    assert_eq!(Duration::from_secs(2).try_mul(-0.0), Ok(Duration::ZERO));
+    assert_eq!(Duration::from_secs(2).try_mul(-1.5), Err(ArithmeticError::Undefined));
+    assert_eq!(Duration::ZERO.try_mul(-1.5), Err(ArithmeticError::Undefined));
+    assert_eq!(Duration::from_secs(2).try_mul(f64::NAN), Err(ArithmeticError::Undefined));
+    assert_eq!(Duration::ZERO.try_mul(f64::INFINITY), Err(ArithmeticError::Undefined));
+    assert_eq!(Duration::from_secs(2).try_mul(f64::INFINITY), Err(ArithmeticError::Overflow));
+    assert_eq!(Duration::MAX.try_mul(2.0), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_duration_try_rem() {
+    assert_eq!(Duration::from_secs(3).try_rem(Duration::from_millis(700)),
+               Ok(Duration::from_millis(200)));
+    assert_eq!(Duration::from_secs(3).try_rem(Duration::ZERO), Err(Undefined));
+}
+
+#[test]
+fn test_duration_try_sub() {
+    assert_eq!(Duration::from_secs(1).try_sub(Duration::from_millis(500)),
+               Ok(Duration::from_millis(500)));
+    assert_eq!(Duration::ZERO.try_sub(Duration::from_nanos(1)), Err(Underflow));
+}
+
+#[test]
+fn test_duration_saturating_ops() {
+    assert_eq!(SaturatingAdd::saturating_add(Duration::MAX, Duration::from_secs(1)), Duration::MAX);
+    assert_eq!(SaturatingMul::saturating_mul(Duration::MAX, 2u32), Duration::MAX);
+    assert_eq!(SaturatingSub::saturating_sub(Duration::ZERO, Duration::from_secs(1)),
+               Duration::ZERO);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_instant_ops() {
+    let now = Instant::now();
+    let later = now.try_add(Duration::from_secs(1)).unwrap();
+    assert_eq!(later.try_sub(now), Ok(Duration::from_secs(1)));
+    assert_eq!(now.try_sub(later), Err(Underflow));
+    assert_eq!(later.try_sub(Duration::from_secs(1)), Ok(now));
+    assert_eq!(now.try_add(Duration::MAX), Err(Overflow));
+    assert_eq!(SaturatingSub::saturating_sub(later, now), Duration::from_secs(1));
+    assert_eq!(SaturatingSub::saturating_sub(now, later), Duration::ZERO);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_system_time_ops() {
+    let epoch = SystemTime::UNIX_EPOCH;
+    let later = epoch.try_add(Duration::from_secs(1)).unwrap();
+    assert_eq!(later.try_sub(epoch), Ok(Duration::from_secs(1)));
+    assert_eq!(epoch.try_sub(later), Err(Underflow));
+    assert_eq!(later.try_sub(Duration::from_secs(1)), Ok(epoch));
+    assert_eq!(epoch.try_add(Duration::MAX), Err(Overflow));
+    assert_eq!(SaturatingSub::saturating_sub(later, epoch), Duration::from_secs(1));
+    assert_eq!(SaturatingSub::saturating_sub(epoch, later), Duration::ZERO);
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::{ArithmeticError, IndexedError};
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+// Operators on tuples are applied element-wise. Since the elements of a tuple may have different
+// error types, checked operators convert them to `ArithmeticError` and report the index of the
+// first element for which the operation failed.
+
+/// Invokes a macro once for each supported tuple length, passing the element type parameters,
+/// right-hand side type parameters, and indices of each element.
+macro_rules! for_each_tuple {
+    ($mac:ident! { $($args:tt)* }) => {
+        $mac! { $($args)* (T0 U0 0) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6) }
+        $mac! { $($args)* (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7) }
+        $mac! {
+            $($args)*
+            (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8)
+        }
+        $mac! {
+            $($args)*
+            (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8,
+             T9 U9 9)
+        }
+        $mac! {
+            $($args)*
+            (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8,
+             T9 U9 9, T10 U10 10)
+        }
+        $mac! {
+            $($args)*
+            (T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8,
+             T9 U9 9, T10 U10 10, T11 U11 11)
+        }
+    };
+}
+
+/// Implements a checked unary operator for a tuple.
+macro_rules! impl_try_unary_op {
+    { $trait:ident::$fn:ident ($($t:ident $u:ident $i:tt),+) } => {
+        impl<$($t),+> $trait for ($($t,)+)
+        where
+            $($t: $trait, $t::Error: Into<ArithmeticError>),+
+        {
+            type Output = ($($t::Output,)+);
+            type Error = IndexedError<ArithmeticError>;
+
+            fn $fn(self) -> Result<Self::Output, IndexedError<ArithmeticError>> {
+                Ok(($(
+                    self.$i.$fn().map_err(|err| IndexedError::new($i, err.into()))?,
+                )+))
+            }
+        }
+    };
+}
+
+/// Implements a checked binary operator for a tuple.
+macro_rules! impl_try_binary_op {
+    { $trait:ident::$fn:ident ($($t:ident $u:ident $i:tt),+) } => {
+        impl<$($t, $u),+> $trait<($($u,)+)> for ($($t,)+)
+        where
+            $($t: $trait<$u>, $t::Error: Into<ArithmeticError>),+
+        {
+            type Output = ($($t::Output,)+);
+            type Error = IndexedError<ArithmeticError>;
+
+            fn $fn(self, rhs: ($($u,)+)) -> Result<Self::Output, IndexedError<ArithmeticError>> {
+                Ok(($(
+                    self.$i.$fn(rhs.$i).map_err(|err| IndexedError::new($i, err.into()))?,
+                )+))
+            }
+        }
+    };
+}
+
+/// Implements a saturating or wrapping unary operator for a tuple.
+macro_rules! impl_unary_op {
+    { $trait:ident::$fn:ident ($($t:ident $u:ident $i:tt),+) } => {
+        impl<$($t: $trait),+> $trait for ($($t,)+) {
+            type Output = ($($t::Output,)+);
+
+            fn $fn(self) -> Self::Output {
+                ($(self.$i.$fn(),)+)
+            }
+        }
+    };
+}
+
+/// Implements a saturating or wrapping binary operator for a tuple.
+macro_rules! impl_binary_op {
+    { $trait:ident::$fn:ident ($($t:ident $u:ident $i:tt),+) } => {
+        impl<$($t: $trait<$u>, $u),+> $trait<($($u,)+)> for ($($t,)+) {
+            type Output = ($($t::Output,)+);
+
+            fn $fn(self, rhs: ($($u,)+)) -> Self::Output {
+                ($(self.$i.$fn(rhs.$i),)+)
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_try_unary_op! { TryNeg::try_neg });
+for_each_tuple!(impl_try_binary_op! { TryAdd::try_add });
+for_each_tuple!(impl_try_binary_op! { TryDiv::try_div });
+for_each_tuple!(impl_try_binary_op! { TryMul::try_mul });
+for_each_tuple!(impl_try_binary_op! { TryRem::try_rem });
+for_each_tuple!(impl_try_binary_op! { TrySub::try_sub });
+for_each_tuple!(impl_unary_op! { SaturatingNeg::saturating_neg });
+for_each_tuple!(impl_binary_op! { SaturatingAdd::saturating_add });
+for_each_tuple!(impl_binary_op! { SaturatingMul::saturating_mul });
+for_each_tuple!(impl_binary_op! { SaturatingSub::saturating_sub });
+for_each_tuple!(impl_unary_op! { WrappingNeg::wrapping_neg });
+for_each_tuple!(impl_binary_op! { WrappingAdd::wrapping_add });
+for_each_tuple!(impl_binary_op! { WrappingMul::wrapping_mul });
+for_each_tuple!(impl_binary_op! { WrappingSub::wrapping_sub });
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_tuple_try_ops() {
+    assert_eq!((1u8, -2i32).try_add((2u8, 3i32)), Ok((3, 1)));
+    assert_eq!((1u8, i32::MIN).try_sub((2u8, 1i32)),
+               Err(IndexedError::new(0, ArithmeticError::Underflow)));
+    assert_eq!((1u8, i32::MIN).try_sub((1u8, 1i32)),
+               Err(IndexedError::new(1, ArithmeticError::Underflow)));
+    assert_eq!((1u8, 2i16, 3i32).try_div((1u8, 2i16, 0i32)),
+               Err(IndexedError::new(2, ArithmeticError::Undefined)));
+    assert_eq!((1i8, -128i8).try_neg(), Err(IndexedError::new(1, ArithmeticError::Overflow)));
+    assert_eq!((1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8)
+                   .try_mul((2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8)),
+               Ok((2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24)));
+}
+
+#[test]
+fn test_tuple_saturating_wrapping_ops() {
+    assert_eq!(SaturatingAdd::saturating_add((250u8, 1i8), (10u8, 127i8)), (255, 127));
+    assert_eq!(SaturatingNeg::saturating_neg((-128i8,)), (127,));
+    assert_eq!(WrappingAdd::wrapping_add((250u8, 1i8), (10u8, 127i8)), (4, -128));
+    assert_eq!(WrappingNeg::wrapping_neg((-128i8, 1u8)), (-128, 255));
+}
@@ -0,0 +1,258 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::error::{ArithmeticError, Undefined};
+
+/// Checked operator which returns the next representable value greater than `self`.
+///
+/// Fails with [ArithmeticError::Overflow] if there is no greater finite value, or with
+/// [ArithmeticError::Undefined] if `self` is not a number.
+pub trait TryNextUp {
+    type Output;
+    type Error;
+
+    fn try_next_up(self) -> Result<Self::Output, Self::Error>;
+}
+
+/// Checked operator which returns the next representable value less than `self`.
+///
+/// Fails with [ArithmeticError::Underflow] if there is no lesser finite value, or with
+/// [ArithmeticError::Undefined] if `self` is not a number.
+pub trait TryNextDown {
+    type Output;
+    type Error;
+
+    fn try_next_down(self) -> Result<Self::Output, Self::Error>;
+}
+
+/// Checked operator which steps `self` by a signed number of units in the last place.
+///
+/// Positive step counts move toward positive infinity and negative step counts move toward negative
+/// infinity. Positive and negative zero are treated as the same value.
+pub trait TryStepUlps<Steps> {
+    type Output;
+    type Error;
+
+    fn try_step_ulps(self, steps: Steps) -> Result<Self::Output, Self::Error>;
+}
+
+/// Operator which returns the number of units in the last place separating two values.
+///
+/// Positive and negative zero are treated as the same value. Fails with [Undefined] if either
+/// operand is not a number.
+pub trait UlpDistance<Rhs = Self> {
+    type Output;
+    type Error;
+
+    fn ulp_distance(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+/// Implements unary operators for reference types.
+macro_rules! impl_unary_ref_ops {
+    { $(impl $trait:ident::$fn:ident for $ty:ident;)* } => { $(
+        impl<'a> $trait for &'a $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self) -> Result<$ty, Self::Error> {
+                $trait::$fn(*self)
+            }
+        }
+    )* };
+}
+
+/// Implements ULP operators for floating point types.
+///
+/// Each value is mapped to a signed key such that adjacent representable values have adjacent keys.
+macro_rules! impl_float_ops {
+    ($($ty:ident: $bits:ident, steps($($steps:ident),*);)*) => { $(
+        impl TryNextUp for $ty {
+            type Output = $ty;
+            type Error = ArithmeticError;
+
+            fn try_next_up(self) -> Result<$ty, ArithmeticError> {
+                self.try_step_ulps(1i64)
+            }
+        }
+
+        impl TryNextDown for $ty {
+            type Output = $ty;
+            type Error = ArithmeticError;
+
+            fn try_next_down(self) -> Result<$ty, ArithmeticError> {
+                self.try_step_ulps(-1i64)
+            }
+        }
+
+        $(
+            impl TryStepUlps<$steps> for $ty {
+                type Output = $ty;
+                type Error = ArithmeticError;
+
+                fn try_step_ulps(self, steps: $steps) -> Result<$ty, ArithmeticError> {
+                    const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                    if self.is_nan() {
+                        return Err(ArithmeticError::Undefined);
+                    } else if steps == 0 {
+                        return Ok(self);
+                    }
+
+                    let max_key = $ty::MAX.to_bits() as i64;
+                    let bits = self.to_bits();
+                    let magnitude = (bits & !SIGN) as i64;
+                    let key = if bits & SIGN == 0 { magnitude } else { -magnitude };
+                    let key = match key.checked_add(i64::from(steps)) {
+                        None if steps > 0 => return Err(ArithmeticError::Overflow),
+                        None => return Err(ArithmeticError::Underflow),
+                        Some(key) => key,
+                    };
+
+                    if key > max_key {
+                        Err(ArithmeticError::Overflow)
+                    } else if key < -max_key {
+                        Err(ArithmeticError::Underflow)
+                    } else if key >= 0 {
+                        Ok($ty::from_bits(key as $bits))
+                    } else {
+                        Ok($ty::from_bits(SIGN | (-key) as $bits))
+                    }
+                }
+            }
+
+            impl<'a> TryStepUlps<$steps> for &'a $ty {
+                type Output = $ty;
+                type Error = ArithmeticError;
+
+                fn try_step_ulps(self, steps: $steps) -> Result<$ty, ArithmeticError> {
+                    TryStepUlps::try_step_ulps(*self, steps)
+                }
+            }
+        )*
+
+        impl UlpDistance for $ty {
+            type Output = $bits;
+            type Error = Undefined;
+
+            fn ulp_distance(self, rhs: $ty) -> Result<$bits, Undefined> {
+                const SIGN: $bits = 1 << ($bits::BITS - 1);
+
+                if self.is_nan() || rhs.is_nan() {
+                    return Err(Undefined);
+                }
+
+                let key = |value: $ty| {
+                    let bits = value.to_bits();
+                    let magnitude = (bits & !SIGN) as i64;
+                    if bits & SIGN == 0 { magnitude } else { -magnitude }
+                };
+                let (lhs, rhs) = (key(self), key(rhs));
+
+                // The true distance always fits in the unsigned type even when the signed
+                // difference does not.
+                Ok(if lhs >= rhs {
+                    (lhs as u64).wrapping_sub(rhs as u64) as $bits
+                } else {
+                    (rhs as u64).wrapping_sub(lhs as u64) as $bits
+                })
+            }
+        }
+
+        impl<'a> UlpDistance<$ty> for &'a $ty {
+            type Output = $bits;
+            type Error = Undefined;
+
+            fn ulp_distance(self, rhs: $ty) -> Result<$bits, Undefined> {
+                UlpDistance::ulp_distance(*self, rhs)
+            }
+        }
+
+        impl<'r> UlpDistance<&'r $ty> for $ty {
+            type Output = $bits;
+            type Error = Undefined;
+
+            fn ulp_distance(self, rhs: &'r $ty) -> Result<$bits, Undefined> {
+                UlpDistance::ulp_distance(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> UlpDistance<&'r $ty> for &'a $ty {
+            type Output = $bits;
+            type Error = Undefined;
+
+            fn ulp_distance(self, rhs: &'r $ty) -> Result<$bits, Undefined> {
+                UlpDistance::ulp_distance(*self, *rhs)
+            }
+        }
+
+        impl_unary_ref_ops! {
+            impl TryNextUp::try_next_up for $ty;
+            impl TryNextDown::try_next_down for $ty;
+        }
+    )* };
+}
+
+impl_float_ops! {
+    f32: u32, steps(i32, i64);
+    f64: u64, steps(i64);
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_try_next_up() {
+    assert_eq!(f32::try_next_up(1.0), Ok(1.0 + f32::EPSILON));
+    assert_eq!(f32::try_next_up(0.0), Ok(f32::from_bits(1)));
+    assert_eq!(f32::try_next_up(-0.0), Ok(f32::from_bits(1)));
+    assert_eq!(f32::try_next_up(-f32::from_bits(1)), Ok(0.0));
+    assert_eq!(f32::try_next_up(f32::NEG_INFINITY), Ok(f32::MIN));
+    assert_eq!(f32::try_next_up(f32::MAX), Err(ArithmeticError::Overflow));
+    assert_eq!(f32::try_next_up(f32::INFINITY), Err(ArithmeticError::Overflow));
+    assert_eq!(f32::try_next_up(f32::NAN), Err(ArithmeticError::Undefined));
+    assert_eq!(f64::try_next_up(1.0), Ok(1.0 + f64::EPSILON));
+    assert_eq!(f64::try_next_up(f64::MAX), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_try_next_down() {
+    assert_eq!(f32::try_next_down(1.0 + f32::EPSILON), Ok(1.0));
+    assert_eq!(f32::try_next_down(0.0), Ok(-f32::from_bits(1)));
+    assert_eq!(f32::try_next_down(f32::INFINITY), Ok(f32::MAX));
+    assert_eq!(f32::try_next_down(f32::MIN), Err(ArithmeticError::Underflow));
+    assert_eq!(f32::try_next_down(f32::NAN), Err(ArithmeticError::Undefined));
+    assert_eq!(f64::try_next_down(f64::MIN), Err(ArithmeticError::Underflow));
+}
+
+#[test]
+fn test_try_step_ulps() {
+    assert_eq!(f32::try_step_ulps(1.0, 0i32), Ok(1.0));
+    assert_eq!(f32::try_step_ulps(f32::INFINITY, 0i32), Ok(f32::INFINITY));
+    assert_eq!(f32::try_step_ulps(f32::from_bits(2), -4i32), Ok(-f32::from_bits(2)));
+    assert_eq!(f32::try_step_ulps(f32::MIN, i32::MAX), Ok(f32::MIN_POSITIVE));
+    assert_eq!(f32::try_step_ulps(f32::MAX, i64::MIN), Err(ArithmeticError::Underflow));
+    assert_eq!(f32::try_step_ulps(0.0, i64::MAX), Err(ArithmeticError::Overflow));
+    assert_eq!(f32::try_step_ulps(f32::MIN, i64::MIN), Err(ArithmeticError::Underflow));
+    assert_eq!(f64::try_step_ulps(1.0, 2i64), Ok(1.0 + 2.0 * f64::EPSILON));
+    assert_eq!(f64::try_step_ulps(f64::MIN, i64::MAX), Ok(f64::MIN_POSITIVE));
+    assert_eq!(f64::try_step_ulps(f64::MAX, 1i64), Err(ArithmeticError::Overflow));
+    assert_eq!(f64::try_step_ulps(f64::NAN, 0i64), Err(ArithmeticError::Undefined));
+}
+
+#[test]
+fn test_ulp_distance() {
+    assert_eq!(f32::ulp_distance(1.0, 1.0), Ok(0));
+    assert_eq!(f32::ulp_distance(0.0, -0.0), Ok(0));
+    assert_eq!(f32::ulp_distance(1.0, 1.0 + f32::EPSILON), Ok(1));
+    assert_eq!(f32::ulp_distance(-f32::from_bits(3), f32::from_bits(4)), Ok(7));
+    assert_eq!(f32::ulp_distance(f32::NEG_INFINITY, f32::INFINITY), Ok(0xff00_0000));
+    assert_eq!(f32::ulp_distance(f32::NAN, 1.0), Err(Undefined));
+    assert_eq!(f64::ulp_distance(f64::MIN, f64::MAX), Ok(0xffdf_ffff_ffff_fffe));
+    assert_eq!(f64::ulp_distance(1.0, f64::NAN), Err(Undefined));
+}
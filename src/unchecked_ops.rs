@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+
+/// Addition operator which assumes that the result does not overflow or underflow.
+pub trait UncheckedAdd<Rhs = Self> {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TryAdd::try_add` would fail with the same operands.
+    unsafe fn unchecked_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Division operator which assumes that the result is defined and in range.
+pub trait UncheckedDiv<Rhs = Self> {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TryDiv::try_div` would fail with the same operands.
+    unsafe fn unchecked_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication operator which assumes that the result does not overflow or underflow.
+pub trait UncheckedMul<Rhs = Self> {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TryMul::try_mul` would fail with the same operands.
+    unsafe fn unchecked_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Negation operator which assumes that the result does not overflow or underflow.
+pub trait UncheckedNeg {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TryNeg::try_neg` would fail with the same operand.
+    unsafe fn unchecked_neg(self) -> Self::Output;
+}
+
+/// Remainder operator which assumes that the result is defined and in range.
+pub trait UncheckedRem<Rhs = Self> {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TryRem::try_rem` would fail with the same operands.
+    unsafe fn unchecked_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtraction operator which assumes that the result does not overflow or underflow.
+pub trait UncheckedSub<Rhs = Self> {
+    type Output;
+
+    /// # Safety
+    ///
+    /// The behavior is undefined if `TrySub::try_sub` would fail with the same operands.
+    unsafe fn unchecked_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+//--------------------------------------------------------------------------------------------------
+
+// Unchecked operators are implemented for every type which implements the corresponding checked
+// operator. The failure branch of the checked operator is marked as unreachable, which lets the
+// compiler remove the check for primitive types.
+
+/// Implements binary unchecked operators.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident => $try_trait:ident::$try_fn:ident;)* } => { $(
+        impl<T: $try_trait<R>, R> $trait<R> for T {
+            type Output = T::Output;
+
+            unsafe fn $fn(self, rhs: R) -> T::Output {
+                // SAFETY: The caller guarantees that the checked operator does not fail.
+                unsafe { $try_trait::$try_fn(self, rhs).unwrap_unchecked() }
+            }
+        }
+    )* };
+}
+
+impl_binary_ops! {
+    impl UncheckedAdd::unchecked_add => TryAdd::try_add;
+    impl UncheckedDiv::unchecked_div => TryDiv::try_div;
+    impl UncheckedMul::unchecked_mul => TryMul::try_mul;
+    impl UncheckedRem::unchecked_rem => TryRem::try_rem;
+    impl UncheckedSub::unchecked_sub => TrySub::try_sub;
+}
+
+impl<T: TryNeg> UncheckedNeg for T {
+    type Output = T::Output;
+
+    unsafe fn unchecked_neg(self) -> T::Output {
+        // SAFETY: The caller guarantees that the checked operator does not fail.
+        unsafe { self.try_neg().unwrap_unchecked() }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_unchecked_ops() {
+    unsafe {
+        assert_eq!(UncheckedAdd::unchecked_add(200u8, 55), 255);
+        assert_eq!(UncheckedDiv::unchecked_div(&7i32, &-2), -3);
+        assert_eq!(UncheckedMul::unchecked_mul(16u16, 16), 256);
+        assert_eq!(UncheckedNeg::unchecked_neg(-127i8), 127);
+        assert_eq!(UncheckedRem::unchecked_rem(7u8, 4), 3);
+        assert_eq!(UncheckedSub::unchecked_sub(7u8, 7), 0);
+    }
+}
+
+#[test]
+fn test_unchecked_ops_match_try_ops() {
+    for a in -8i8..=8 {
+        for b in -8i8..=8 {
+            unsafe {
+                assert_eq!(UncheckedAdd::unchecked_add(&a, &b), TryAdd::try_add(a, b).unwrap());
+                assert_eq!(UncheckedMul::unchecked_mul(a, &b), TryMul::try_mul(a, b).unwrap());
+                assert_eq!(UncheckedSub::unchecked_sub(&a, b), TrySub::try_sub(a, b).unwrap());
+                if b != 0 {
+                    assert_eq!(UncheckedDiv::unchecked_div(&a, &b), TryDiv::try_div(a, b).unwrap());
+                    assert_eq!(UncheckedRem::unchecked_rem(&a, &b), TryRem::try_rem(a, b).unwrap());
+                }
+            }
+        }
+        unsafe {
+            assert_eq!(UncheckedNeg::unchecked_neg(&a), TryNeg::try_neg(a).unwrap());
+        }
+    }
+}
@@ -0,0 +1,296 @@
+/*
+ * Copyright (c) 2023 Martin Mills <daggerbot@gmail.com>
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use core::convert::Infallible;
+use core::num::{Saturating, Wrapping};
+
+use crate::error::Undefined;
+use crate::saturating_ops::{SaturatingAdd, SaturatingMul, SaturatingNeg, SaturatingSub};
+use crate::try_ops::{TryAdd, TryDiv, TryMul, TryNeg, TryRem, TrySub};
+use crate::wrapping_ops::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
+
+// The checked operators for `Wrapping<T>` and `Saturating<T>` follow the overflow behavior of the
+// wrapper type, so they only fail when the result is undefined. The saturating and wrapping
+// operators are applied to the inner value regardless of the wrapper type.
+
+/// Implements checked unary operators for reference types.
+macro_rules! impl_try_unary_ref_ops {
+    { $(impl $trait:ident::$fn:ident for $ty:ty;)* } => { $(
+        impl<'a> $trait for &'a $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self) -> Result<$ty, Self::Error> {
+                $trait::$fn(*self)
+            }
+        }
+    )* };
+}
+
+/// Implements checked binary operators for reference types.
+macro_rules! impl_try_binary_ref_ops {
+    { $(impl $trait:ident::$fn:ident for $ty:ty;)* } => { $(
+        impl<'a> $trait<$ty> for &'a $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self, rhs: $ty) -> Result<$ty, Self::Error> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $ty> for $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self, rhs: &'r $ty) -> Result<$ty, Self::Error> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $ty> for &'a $ty {
+            type Output = $ty;
+            type Error = <$ty as $trait>::Error;
+
+            fn $fn(self, rhs: &'r $ty) -> Result<$ty, Self::Error> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements unary saturating or wrapping operators by applying them to the inner value.
+macro_rules! impl_unary_ops {
+    { $(impl $trait:ident::$fn:ident for $wrapper:ident<$ty:ident>;)* } => { $(
+        impl $trait for $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self) -> $wrapper<$ty> {
+                $wrapper($trait::$fn(self.0))
+            }
+        }
+
+        impl<'a> $trait for &'a $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self) -> $wrapper<$ty> {
+                $trait::$fn(*self)
+            }
+        }
+    )* };
+}
+
+/// Implements binary saturating or wrapping operators by applying them to the inner values.
+macro_rules! impl_binary_ops {
+    { $(impl $trait:ident::$fn:ident for $wrapper:ident<$ty:ident>;)* } => { $(
+        impl $trait for $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self, rhs: $wrapper<$ty>) -> $wrapper<$ty> {
+                $wrapper($trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<$wrapper<$ty>> for &'a $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self, rhs: $wrapper<$ty>) -> $wrapper<$ty> {
+                $trait::$fn(*self, rhs)
+            }
+        }
+
+        impl<'r> $trait<&'r $wrapper<$ty>> for $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self, rhs: &'r $wrapper<$ty>) -> $wrapper<$ty> {
+                $trait::$fn(self, *rhs)
+            }
+        }
+
+        impl<'a, 'r> $trait<&'r $wrapper<$ty>> for &'a $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+
+            fn $fn(self, rhs: &'r $wrapper<$ty>) -> $wrapper<$ty> {
+                $trait::$fn(*self, *rhs)
+            }
+        }
+    )* };
+}
+
+/// Implements checked operators which cannot fail.
+macro_rules! impl_infallible_ops {
+    {
+        $(impl $trait:ident::$fn:ident($($rhs:ident)?) for $wrapper:ident<$ty:ident> = $op:ident;)*
+    } => { $(
+        impl $trait for $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+            type Error = Infallible;
+
+            fn $fn(self $(, $rhs: $wrapper<$ty>)?) -> Result<$wrapper<$ty>, Infallible> {
+                Ok($wrapper(self.0.$op($($rhs.0)?)))
+            }
+        }
+    )* };
+}
+
+/// Implements checked division operators which only fail when dividing by zero.
+macro_rules! impl_division_ops {
+    { $(impl $trait:ident::$fn:ident for $wrapper:ident<$ty:ident> = $op:ident;)* } => { $(
+        impl $trait for $wrapper<$ty> {
+            type Output = $wrapper<$ty>;
+            type Error = Undefined;
+
+            fn $fn(self, rhs: $wrapper<$ty>) -> Result<$wrapper<$ty>, Undefined> {
+                if rhs.0 == 0 {
+                    Err(Undefined)
+                } else {
+                    Ok($wrapper(self.0.$op(rhs.0)))
+                }
+            }
+        }
+    )* };
+}
+
+/// Implements operators which are common to `Wrapping<T>` and `Saturating<T>` for all integer
+/// types.
+macro_rules! impl_common_ops {
+    ($($wrapper:ident<$ty:ident>),*) => { $(
+        impl_binary_ops! {
+            impl SaturatingAdd::saturating_add for $wrapper<$ty>;
+            impl SaturatingMul::saturating_mul for $wrapper<$ty>;
+            impl SaturatingSub::saturating_sub for $wrapper<$ty>;
+            impl WrappingAdd::wrapping_add for $wrapper<$ty>;
+            impl WrappingMul::wrapping_mul for $wrapper<$ty>;
+            impl WrappingSub::wrapping_sub for $wrapper<$ty>;
+        }
+
+        impl_unary_ops! {
+            impl WrappingNeg::wrapping_neg for $wrapper<$ty>;
+        }
+
+        impl_try_unary_ref_ops! {
+            impl TryNeg::try_neg for $wrapper<$ty>;
+        }
+
+        impl_try_binary_ref_ops! {
+            impl TryAdd::try_add for $wrapper<$ty>;
+            impl TryDiv::try_div for $wrapper<$ty>;
+            impl TryMul::try_mul for $wrapper<$ty>;
+            impl TryRem::try_rem for $wrapper<$ty>;
+            impl TrySub::try_sub for $wrapper<$ty>;
+        }
+    )* };
+}
+
+/// Implements operators for `Wrapping<T>` and `Saturating<T>` where `T` is a signed integer type.
+macro_rules! impl_int_ops {
+    ($($ty:ident),*) => { $(
+        impl_infallible_ops! {
+            impl TryAdd::try_add(rhs) for Wrapping<$ty> = wrapping_add;
+            impl TryMul::try_mul(rhs) for Wrapping<$ty> = wrapping_mul;
+            impl TryNeg::try_neg() for Wrapping<$ty> = wrapping_neg;
+            impl TrySub::try_sub(rhs) for Wrapping<$ty> = wrapping_sub;
+            impl TryAdd::try_add(rhs) for Saturating<$ty> = saturating_add;
+            impl TryMul::try_mul(rhs) for Saturating<$ty> = saturating_mul;
+            impl TryNeg::try_neg() for Saturating<$ty> = saturating_neg;
+            impl TrySub::try_sub(rhs) for Saturating<$ty> = saturating_sub;
+        }
+
+        impl_division_ops! {
+            impl TryDiv::try_div for Wrapping<$ty> = wrapping_div;
+            impl TryRem::try_rem for Wrapping<$ty> = wrapping_rem;
+            impl TryDiv::try_div for Saturating<$ty> = saturating_div;
+            // `$ty::MIN % -1` is zero, so the remainder never saturates.
+            impl TryRem::try_rem for Saturating<$ty> = wrapping_rem;
+        }
+
+        impl_unary_ops! {
+            impl SaturatingNeg::saturating_neg for Wrapping<$ty>;
+            impl SaturatingNeg::saturating_neg for Saturating<$ty>;
+        }
+
+        impl_common_ops!(Wrapping<$ty>, Saturating<$ty>);
+    )* };
+}
+
+impl_int_ops!(i8, i16, i32, i64, i128, isize);
+
+/// Implements operators for `Wrapping<T>` and `Saturating<T>` where `T` is an unsigned integer
+/// type.
+macro_rules! impl_uint_ops {
+    ($($ty:ident),*) => { $(
+        impl_infallible_ops! {
+            impl TryAdd::try_add(rhs) for Wrapping<$ty> = wrapping_add;
+            impl TryMul::try_mul(rhs) for Wrapping<$ty> = wrapping_mul;
+            impl TryNeg::try_neg() for Wrapping<$ty> = wrapping_neg;
+            impl TrySub::try_sub(rhs) for Wrapping<$ty> = wrapping_sub;
+            impl TryAdd::try_add(rhs) for Saturating<$ty> = saturating_add;
+            impl TryMul::try_mul(rhs) for Saturating<$ty> = saturating_mul;
+            impl TrySub::try_sub(rhs) for Saturating<$ty> = saturating_sub;
+        }
+
+        impl TryNeg for Saturating<$ty> {
+            type Output = Saturating<$ty>;
+            type Error = Infallible;
+
+            fn try_neg(self) -> Result<Saturating<$ty>, Infallible> {
+                // The negation of any unsigned value is either zero or saturates to zero.
+                Ok(Saturating(0))
+            }
+        }
+
+        impl_division_ops! {
+            impl TryDiv::try_div for Wrapping<$ty> = wrapping_div;
+            impl TryRem::try_rem for Wrapping<$ty> = wrapping_rem;
+            impl TryDiv::try_div for Saturating<$ty> = wrapping_div;
+            impl TryRem::try_rem for Saturating<$ty> = wrapping_rem;
+        }
+
+        impl_common_ops!(Wrapping<$ty>, Saturating<$ty>);
+    )* };
+}
+
+impl_uint_ops!(u8, u16, u32, u64, u128, usize);
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_wrapping_try_ops() {
+    assert_eq!(Wrapping(100i8).try_add(Wrapping(28)), Ok(Wrapping(-128)));
+    assert_eq!(Wrapping(200u8).try_add(Wrapping(56)), Ok(Wrapping(0)));
+    assert_eq!(Wrapping(0u8).try_sub(Wrapping(1)), Ok(Wrapping(255)));
+    assert_eq!(Wrapping(16u8).try_mul(Wrapping(16)), Ok(Wrapping(0)));
+    assert_eq!(Wrapping(-128i8).try_neg(), Ok(Wrapping(-128)));
+    assert_eq!(Wrapping(1u8).try_neg(), Ok(Wrapping(255)));
+    assert_eq!(Wrapping(-128i8).try_div(Wrapping(-1)), Ok(Wrapping(-128)));
+    assert_eq!(Wrapping(-128i8).try_rem(Wrapping(-1)), Ok(Wrapping(0)));
+    assert_eq!(Wrapping(1i8).try_div(Wrapping(0)), Err(Undefined));
+    assert_eq!(Wrapping(1u8).try_rem(Wrapping(0)), Err(Undefined));
+}
+
+#[test]
+fn test_saturating_try_ops() {
+    assert_eq!(Saturating(100i8).try_add(Saturating(28)), Ok(Saturating(127)));
+    assert_eq!(Saturating(200u8).try_add(Saturating(56)), Ok(Saturating(255)));
+    assert_eq!(Saturating(0u8).try_sub(Saturating(1)), Ok(Saturating(0)));
+    assert_eq!(Saturating(43i8).try_mul(Saturating(-3)), Ok(Saturating(-128)));
+    assert_eq!(Saturating(-128i8).try_neg(), Ok(Saturating(127)));
+    assert_eq!(Saturating(1u8).try_neg(), Ok(Saturating(0)));
+    assert_eq!(Saturating(-128i8).try_div(Saturating(-1)), Ok(Saturating(127)));
+    assert_eq!(Saturating(-128i8).try_rem(Saturating(-1)), Ok(Saturating(0)));
+    assert_eq!(Saturating(1i8).try_div(Saturating(0)), Err(Undefined));
+    assert_eq!(Saturating(1u8).try_rem(Saturating(0)), Err(Undefined));
+}
+
+#[test]
+fn test_wrapper_explicit_ops() {
+    assert_eq!(SaturatingAdd::saturating_add(Wrapping(200u8), Wrapping(56)), Wrapping(255));
+    assert_eq!(SaturatingNeg::saturating_neg(Wrapping(-128i8)), Wrapping(127));
+    assert_eq!(WrappingAdd::wrapping_add(Saturating(200u8), Saturating(56)), Saturating(0));
+    assert_eq!(WrappingNeg::wrapping_neg(Saturating(-128i8)), Saturating(-128));
+}